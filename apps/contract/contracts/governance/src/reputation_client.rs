@@ -2,37 +2,64 @@
 //!
 //! Used to look up a voter's NFT tier level, which determines their vote weight.
 
-use soroban_sdk::{Address, ConversionError, Env, IntoVal, InvokeError, Symbol};
+use soroban_sdk::{
+    contracttype, Address, ConversionError, Env, IntoVal, InvokeError, String, Symbol, Vec,
+};
 
 use crate::types::NftTier;
 
+/// Level definition as returned by the Reputation contract's `get_level_table`.
+/// Must match the Reputation contract's `LevelDefinition`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelDefinition {
+    pub id: u32,
+    pub name: String,
+    pub threshold: u32,
+}
+
 /// Client for reading from the KindFi Reputation contract.
 pub struct ReputationClient;
 
 impl ReputationClient {
-    /// Query a user's current reputation level from the Reputation contract.
+    /// Query a user's current NFT tier from the Reputation contract.
     ///
-    /// The level maps to NFT tiers:
-    /// - 0 = Rookie  (not eligible)
-    /// - 1 = Bronze  (1 vote weight)
-    /// - 2 = Silver  (3 vote weight)
-    /// - 3 = Gold    (5 vote weight)
-    /// - 4 = Diamond (10 vote weight)
+    /// Level ids are configurable, so the tier follows the position of the
+    /// user's level in the level table (ordered by threshold) rather than its
+    /// id:
+    /// - 1st level = Rookie  (not eligible)
+    /// - 2nd level = Bronze  (1 vote weight)
+    /// - 3rd level = Silver  (3 vote weight)
+    /// - 4th level = Gold    (5 vote weight)
+    /// - 5th level and above = Diamond (10 vote weight)
     ///
     /// # Returns
     /// * `Some(NftTier)` — tier resolved from the on-chain level
     /// * `None` — cross-contract call failed
     pub fn get_tier(e: &Env, reputation_contract: &Address, user: &Address) -> Option<NftTier> {
-        let result: Result<Result<u32, ConversionError>, Result<InvokeError, InvokeError>> =
+        let level: Result<Result<u32, ConversionError>, Result<InvokeError, InvokeError>> =
             e.try_invoke_contract(
                 reputation_contract,
                 &Symbol::new(e, "get_level"),
                 (user,).into_val(e),
             );
+        let Ok(Ok(level)) = level else {
+            return None;
+        };
+
+        let levels: Result<
+            Result<Vec<LevelDefinition>, ConversionError>,
+            Result<InvokeError, InvokeError>,
+        > = e.try_invoke_contract(
+            reputation_contract,
+            &Symbol::new(e, "get_level_table"),
+            Vec::new(e),
+        );
+        let Ok(Ok(levels)) = levels else {
+            return None;
+        };
 
-        match result {
-            Ok(Ok(level)) => Some(NftTier::from_level(level)),
-            _ => None,
-        }
+        let position = levels.iter().position(|l| l.id == level).unwrap_or(0);
+        Some(NftTier::from_position(position as u32))
     }
 }
@@ -3,8 +3,11 @@
 extern crate std;
 
 use super::*;
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, String, Vec,
+};
 
+use crate::reputation_client::LevelDefinition;
 use crate::types::{RoundStatus, VoteType};
 
 // ============================================================================
@@ -193,13 +196,78 @@ fn test_nft_tier_vote_weight_values() {
 }
 
 #[test]
-fn test_nft_tier_from_level_mapping() {
-    assert_eq!(NftTier::from_level(0), NftTier::Rookie);
-    assert_eq!(NftTier::from_level(1), NftTier::Bronze);
-    assert_eq!(NftTier::from_level(2), NftTier::Silver);
-    assert_eq!(NftTier::from_level(3), NftTier::Gold);
-    assert_eq!(NftTier::from_level(4), NftTier::Diamond);
-    assert_eq!(NftTier::from_level(99), NftTier::Rookie); // unknown → Rookie
+fn test_nft_tier_from_position_mapping() {
+    assert_eq!(NftTier::from_position(0), NftTier::Rookie);
+    assert_eq!(NftTier::from_position(1), NftTier::Bronze);
+    assert_eq!(NftTier::from_position(2), NftTier::Silver);
+    assert_eq!(NftTier::from_position(3), NftTier::Gold);
+    assert_eq!(NftTier::from_position(4), NftTier::Diamond);
+    assert_eq!(NftTier::from_position(7), NftTier::Diamond); // extra levels → Diamond
+}
+
+/// Minimal stand-in for the Reputation contract, exposing the entry points
+/// the Governance contract calls.
+#[contract]
+struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn get_level(e: Env, user: Address) -> u32 {
+        e.storage().persistent().get(&user).unwrap_or(0)
+    }
+
+    pub fn get_level_table(e: Env) -> Vec<LevelDefinition> {
+        e.storage()
+            .instance()
+            .get(&symbol_short!("levels"))
+            .unwrap()
+    }
+
+    pub fn set_level(e: Env, user: Address, level: u32) {
+        e.storage().persistent().set(&user, &level);
+    }
+
+    pub fn set_level_table(e: Env, levels: Vec<LevelDefinition>) {
+        e.storage()
+            .instance()
+            .set(&symbol_short!("levels"), &levels);
+    }
+}
+
+#[test]
+fn test_voter_tier_follows_level_table_position() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let reputation_id = env.register(MockReputation, ());
+    let reputation = MockReputationClient::new(&env, &reputation_id);
+    let contract = GovernanceClient::new(&env, &env.register_contract(None, Governance));
+    contract.__constructor(&admin, &reputation_id);
+
+    // Level ids that do not match the default 0..4 numbering
+    let level = |id: u32, name: &str, threshold: u32| LevelDefinition {
+        id,
+        name: String::from_str(&env, name),
+        threshold,
+    };
+    reputation.set_level_table(&Vec::from_array(
+        &env,
+        [
+            level(10, "Newcomer", 0),
+            level(20, "Helper", 100),
+            level(30, "Champion", 500),
+        ],
+    ));
+
+    let newcomer = Address::generate(&env);
+    let helper = Address::generate(&env);
+    let champion = Address::generate(&env);
+    reputation.set_level(&newcomer, &10);
+    reputation.set_level(&helper, &20);
+    reputation.set_level(&champion, &30);
+
+    assert_eq!(contract.get_voter_tier(&newcomer), Some(NftTier::Rookie));
+    assert_eq!(contract.get_voter_tier(&helper), Some(NftTier::Bronze));
+    assert_eq!(contract.get_voter_tier(&champion), Some(NftTier::Silver));
 }
 
 // ============================================================================
@@ -28,32 +28,34 @@ pub enum VoteType {
     Down = 1,
 }
 
-/// NFT tier mapped from the Reputation contract level (0–4).
+/// NFT tier mapped from the position of the user's level in the Reputation
+/// contract's level table.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum NftTier {
-    /// Level 0 — not eligible to vote
+    /// 1st level — not eligible to vote
     Rookie = 0,
-    /// Level 1 — 1 vote weight
+    /// 2nd level — 1 vote weight
     Bronze = 1,
-    /// Level 2 — 3 vote weight
+    /// 3rd level — 3 vote weight
     Silver = 2,
-    /// Level 3 — 5 vote weight
+    /// 4th level — 5 vote weight
     Gold = 3,
-    /// Level 4 — 10 vote weight
+    /// 5th level and above — 10 vote weight
     Diamond = 4,
 }
 
 impl NftTier {
-    /// Convert a Reputation contract level (u32) to an NftTier.
-    pub fn from_level(level: u32) -> NftTier {
-        match level {
+    /// Convert the 0-based position of a level in the Reputation contract's
+    /// level table to an NftTier. Levels past the fifth are all Diamond.
+    pub fn from_position(position: u32) -> NftTier {
+        match position {
+            0 => NftTier::Rookie,
             1 => NftTier::Bronze,
             2 => NftTier::Silver,
             3 => NftTier::Gold,
-            4 => NftTier::Diamond,
-            _ => NftTier::Rookie,
+            _ => NftTier::Diamond,
         }
     }
 
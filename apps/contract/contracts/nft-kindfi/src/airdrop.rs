@@ -0,0 +1,107 @@
+use soroban_sdk::{panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::errors::Error;
+use crate::events::AirdropClaimedEventData;
+use crate::mint;
+use crate::types::{AirdropConfig, StorageKey};
+
+/// TTL constants for claim records (30 days in ledgers)
+const DAY_IN_LEDGERS: u32 = 17280;
+const CLAIM_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const CLAIM_TTL_THRESHOLD: u32 = CLAIM_TTL_AMOUNT - DAY_IN_LEDGERS;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Get the current airdrop configuration.
+pub fn get_config(e: &Env) -> Option<AirdropConfig> {
+    e.storage().instance().get(&StorageKey::AirdropConfig)
+}
+
+/// Store the airdrop configuration.
+pub fn set_config(e: &Env, config: &AirdropConfig) {
+    e.storage().instance().set(&StorageKey::AirdropConfig, config);
+}
+
+/// Get the airdrop configuration or fail if no root was published.
+pub fn require_config(e: &Env) -> AirdropConfig {
+    match get_config(e) {
+        Some(config) => config,
+        None => panic_with_error!(e, Error::AirdropNotConfigured),
+    }
+}
+
+// ============================================================================
+// Merkle Proofs
+// ============================================================================
+
+/// Compute the leaf for an allowlist entry: sha256(xdr((address, template_id))).
+pub fn leaf(e: &Env, claimant: &Address, template_id: u32) -> BytesN<32> {
+    let payload = (claimant.clone(), template_id).to_xdr(e);
+    e.crypto().sha256(&payload).to_bytes()
+}
+
+/// Hash two nodes in sorted order.
+fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from(first.clone());
+    data.append(&Bytes::from(second.clone()));
+    e.crypto().sha256(&data).to_bytes()
+}
+
+/// Check that `proof` links `leaf` to `root`.
+fn verify_proof(e: &Env, root: &BytesN<32>, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut computed = leaf.clone();
+    for sibling in proof.iter() {
+        computed = hash_pair(e, &computed, &sibling);
+    }
+    computed == *root
+}
+
+// ============================================================================
+// Claiming
+// ============================================================================
+
+/// Check whether an allowlist leaf has already been claimed.
+pub fn is_claimed(e: &Env, leaf: &BytesN<32>) -> bool {
+    e.storage()
+        .persistent()
+        .has(&StorageKey::AirdropClaimed(leaf.clone()))
+}
+
+/// Verify an allowlist claim, mark the leaf claimed and mint from the template.
+/// Returns the minted token ID.
+pub fn claim(e: &Env, claimant: &Address, template_id: u32, proof: &Vec<BytesN<32>>) -> u32 {
+    let config = require_config(e);
+
+    let now = e.ledger().timestamp();
+    if now < config.claims_open_at || now >= config.claims_close_at {
+        panic_with_error!(e, Error::AirdropClosed);
+    }
+
+    let leaf = leaf(e, claimant, template_id);
+    if is_claimed(e, &leaf) {
+        panic_with_error!(e, Error::AirdropAlreadyClaimed);
+    }
+    if !verify_proof(e, &config.merkle_root, &leaf, proof) {
+        panic_with_error!(e, Error::InvalidMerkleProof);
+    }
+
+    let key = StorageKey::AirdropClaimed(leaf);
+    e.storage().persistent().set(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, CLAIM_TTL_THRESHOLD, CLAIM_TTL_AMOUNT);
+
+    let token_id = mint::mint_from_template(e, claimant, template_id);
+
+    AirdropClaimedEventData {
+        token_id,
+        claimant: claimant.clone(),
+        template_id,
+    }
+    .publish(e);
+
+    token_id
+}
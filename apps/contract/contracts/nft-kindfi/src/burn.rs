@@ -1,39 +1,125 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env};
 use stellar_tokens::non_fungible::Base;
 
-use crate::events::{BurnedEventData};
+use crate::errors::Error;
+use crate::events::{BurnedEventData, ReissuedEventData};
+use crate::hooks;
 use crate::metadata;
+use crate::types::{NFTMetadata, StorageKey, Tombstone};
+
+/// TTL constants for tombstone storage (30 days in ledgers)
+const DAY_IN_LEDGERS: u32 = 17280;
+const TOMBSTONE_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const TOMBSTONE_TTL_THRESHOLD: u32 = TOMBSTONE_TTL_AMOUNT - DAY_IN_LEDGERS;
 
 /// Burn an NFT owned by the caller.
-/// Removes the token and its associated metadata.
+/// Removes the token and its associated metadata, leaving a tombstone.
 pub fn burn(e: &Env, from: &Address, token_id: u32) {
+    let tombstone = create_tombstone(e, from, token_id);
+
     // Burn using OpenZeppelin Base implementation
     // This handles ownership verification and balance updates
     Base::burn(e, from, token_id);
 
-    // Remove custom metadata
-    metadata::remove_metadata(e, token_id);
-
-    // Emit burned event
-    BurnedEventData {
-        token_id,
-        from: from.clone(),
-    }.publish(e);
+    finish_burn(e, from, token_id, &tombstone);
 }
 
 /// Burn an NFT from another address (requires approval).
 /// The spender must be approved to transfer the token.
 pub fn burn_from(e: &Env, spender: &Address, from: &Address, token_id: u32) {
+    let tombstone = create_tombstone(e, from, token_id);
+
     // Burn using OpenZeppelin Base implementation
     // This handles approval verification, ownership checks, and balance updates
     Base::burn_from(e, spender, from, token_id);
 
+    finish_burn(e, from, token_id, &tombstone);
+}
+
+/// Get the number of tokens currently burned.
+pub fn get_burned_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&StorageKey::BurnedCount)
+        .unwrap_or(0)
+}
+
+/// Get the tombstone of a burned token, if any.
+pub fn get_tombstone(e: &Env, token_id: u32) -> Option<Tombstone> {
+    let key = StorageKey::Tombstone(token_id);
+    let tombstone: Option<Tombstone> = e.storage().persistent().get(&key);
+    if tombstone.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, TOMBSTONE_TTL_THRESHOLD, TOMBSTONE_TTL_AMOUNT);
+    }
+    tombstone
+}
+
+/// Re-issue a burned token under its original ID.
+/// The metadata must hash to the value recorded in the tombstone, so the
+/// badge comes back exactly as it was burned. The hook is notified as for a
+/// transfer from the last owner.
+pub fn reissue(e: &Env, token_id: u32, to: &Address, nft_metadata: &NFTMetadata) {
+    let Some(tombstone) = get_tombstone(e, token_id) else {
+        panic_with_error!(e, Error::TokenNotBurned);
+    };
+    if metadata::hash_metadata(e, nft_metadata) != tombstone.metadata_hash {
+        panic_with_error!(e, Error::TombstoneMetadataMismatch);
+    }
+
+    Base::mint(e, to, token_id);
+    metadata::set_metadata(e, token_id, nft_metadata);
+
+    e.storage()
+        .persistent()
+        .remove(&StorageKey::Tombstone(token_id));
+    set_burned_count(e, get_burned_count(e).saturating_sub(1));
+
+    ReissuedEventData {
+        token_id,
+        to: to.clone(),
+        previous_owner: tombstone.last_owner.clone(),
+    }.publish(e);
+
+    hooks::notify_transfer(e, token_id, &tombstone.last_owner, Some(to));
+}
+
+/// Build the tombstone of a token before it is burned.
+fn create_tombstone(e: &Env, from: &Address, token_id: u32) -> Tombstone {
+    let Some(current) = metadata::get_metadata(e, token_id) else {
+        panic_with_error!(e, Error::MetadataNotFound);
+    };
+    Tombstone {
+        last_owner: from.clone(),
+        burned_at_ledger: e.ledger().sequence(),
+        metadata_hash: metadata::hash_metadata(e, &current),
+    }
+}
+
+/// Remove the token's metadata, store its tombstone and emit the burn event.
+fn finish_burn(e: &Env, from: &Address, token_id: u32, tombstone: &Tombstone) {
     // Remove custom metadata
     metadata::remove_metadata(e, token_id);
 
+    let key = StorageKey::Tombstone(token_id);
+    e.storage().persistent().set(&key, tombstone);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, TOMBSTONE_TTL_THRESHOLD, TOMBSTONE_TTL_AMOUNT);
+    set_burned_count(e, get_burned_count(e) + 1);
+
     // Emit burned event
     BurnedEventData {
         token_id,
         from: from.clone(),
     }.publish(e);
+
+    hooks::notify_transfer(e, token_id, from, None);
+}
+
+fn set_burned_count(e: &Env, count: u32) {
+    e.storage()
+        .instance()
+        .set(&StorageKey::BurnedCount, &count);
 }
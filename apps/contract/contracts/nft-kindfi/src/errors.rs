@@ -16,6 +16,66 @@ pub enum Error {
     TokenNotFound = 303,
     /// Caller does not have required role
     Unauthorized = 304,
-    /// Invalid metadata provided
-    InvalidMetadata = 305,
+    /// Two attributes on the same token share a trait_type
+    DuplicateTraitType = 306,
+    /// No attribute with the given trait_type exists on the token
+    AttributeNotFound = 307,
+    /// The update would change a frozen metadata field
+    FieldFrozen = 308,
+    /// Metadata name is empty
+    EmptyName = 309,
+    /// Metadata name exceeds the maximum length
+    NameTooLong = 310,
+    /// Metadata description exceeds the maximum length
+    DescriptionTooLong = 311,
+    /// Metadata image URI is empty
+    EmptyImageUri = 312,
+    /// A URI exceeds the maximum length
+    UriTooLong = 313,
+    /// A URI does not use an allowed scheme (ipfs:// or https://)
+    InvalidUriScheme = 314,
+    /// Metadata has more attributes than allowed
+    TooManyAttributes = 315,
+    /// Attribute trait_type is empty or too long
+    InvalidTraitType = 316,
+    /// Attribute value is empty or too long
+    InvalidAttributeValue = 317,
+    /// Attribute display_type is not a SEP-0050 display type
+    InvalidDisplayType = 318,
+    /// Attribute value is not numeric although its display_type is numeric
+    InvalidNumericValue = 319,
+    /// Attribute max_value is not numeric
+    InvalidMaxValue = 320,
+    /// Voucher signer key is not registered or its minter lost the role
+    UnknownVoucherSigner = 321,
+    /// Voucher expiry has passed
+    VoucherExpired = 322,
+    /// Voucher nonce has already been used by this signer
+    VoucherNonceUsed = 323,
+    /// Submitted metadata does not match the voucher's metadata hash
+    VoucherMetadataMismatch = 324,
+    /// Metadata template does not exist
+    TemplateNotFound = 325,
+    /// Metadata template ID is already in use
+    TemplateAlreadyExists = 326,
+    /// No airdrop Merkle root has been published
+    AirdropNotConfigured = 327,
+    /// Airdrop claim window is not open
+    AirdropClosed = 328,
+    /// Merkle proof does not match the airdrop root
+    InvalidMerkleProof = 329,
+    /// Airdrop leaf has already been claimed
+    AirdropAlreadyClaimed = 330,
+    /// Claim window closes before it opens
+    InvalidClaimWindow = 331,
+    /// Royalty basis points exceed 10000
+    InvalidRoyalty = 332,
+    /// Sale price is negative or royalty calculation overflows
+    InvalidSalePrice = 333,
+    /// Token has no tombstone (it was never burned or was re-issued)
+    TokenNotBurned = 334,
+    /// Submitted metadata does not match the tombstone's metadata hash
+    TombstoneMetadataMismatch = 335,
+    /// Numeric attribute value is greater than its max_value
+    ValueExceedsMax = 336,
 }
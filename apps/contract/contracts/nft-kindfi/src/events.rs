@@ -1,6 +1,8 @@
-use soroban_sdk::{contractevent, Address};
+use soroban_sdk::{contractevent, Address, BytesN, String};
 
-use crate::types::NFTMetadata;
+use crate::types::{
+    AirdropConfig, ContractMetadata, FrozenFields, NFTAttribute, NFTMetadata, RoyaltyInfo,
+};
 
 /// Event data emitted when an NFT is minted with metadata.
 #[contractevent]
@@ -37,3 +39,149 @@ pub struct MetadataUpdatedEventData {
     pub token_id: u32,
     pub metadata: NFTMetadata,
 }
+
+/// Event data emitted when a single attribute is added or replaced on an NFT.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttributeSetEventData {
+    #[topic]
+    pub token_id: u32,
+    #[topic]
+    pub trait_type: String,
+    pub attribute: NFTAttribute,
+}
+
+/// Event data emitted when a single attribute is removed from an NFT.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttributeRemovedEventData {
+    #[topic]
+    pub token_id: u32,
+    #[topic]
+    pub trait_type: String,
+}
+
+/// Event data emitted when fields are frozen for a single token.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFieldsFrozenEventData {
+    #[topic]
+    pub token_id: u32,
+    /// Fields frozen for the token after this update
+    pub fields: FrozenFields,
+}
+
+/// Event data emitted when fields are frozen for the whole collection.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionFieldsFrozenEventData {
+    #[topic]
+    pub admin: Address,
+    /// Fields frozen collection-wide after this update
+    pub fields: FrozenFields,
+}
+
+/// Event data emitted when a voucher signer key is registered.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherSignerRegisteredEventData {
+    #[topic]
+    pub public_key: BytesN<32>,
+    pub registered_by: Address,
+}
+
+/// Event data emitted when a voucher signer key is removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherSignerRemovedEventData {
+    #[topic]
+    pub public_key: BytesN<32>,
+    pub removed_by: Address,
+}
+
+/// Event data emitted when an NFT is minted from a signed voucher.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherClaimedEventData {
+    #[topic]
+    pub token_id: u32,
+    pub recipient: Address,
+    pub signer: BytesN<32>,
+    pub nonce: u64,
+}
+
+/// Event data emitted when a metadata template is created.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateCreatedEventData {
+    #[topic]
+    pub template_id: u32,
+    pub metadata: NFTMetadata,
+}
+
+/// Event data emitted when the airdrop root or claim window changes.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropUpdatedEventData {
+    #[topic]
+    pub admin: Address,
+    pub config: AirdropConfig,
+}
+
+/// Event data emitted when an airdrop badge is claimed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropClaimedEventData {
+    #[topic]
+    pub token_id: u32,
+    #[topic]
+    pub claimant: Address,
+    pub template_id: u32,
+}
+
+/// Event data emitted when a burned token is re-issued to a new owner.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReissuedEventData {
+    #[topic]
+    pub token_id: u32,
+    #[topic]
+    pub to: Address,
+    pub previous_owner: Address,
+}
+
+/// Event data emitted when the transfer hook is set or removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferHookUpdatedEventData {
+    #[topic]
+    pub admin: Address,
+    pub hook: Option<Address>,
+}
+
+/// Event data emitted when the collection default royalty is set or removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultRoyaltyUpdatedEventData {
+    #[topic]
+    pub admin: Address,
+    pub royalty: Option<RoyaltyInfo>,
+}
+
+/// Event data emitted when a token's royalty override is set or removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRoyaltyUpdatedEventData {
+    #[topic]
+    pub token_id: u32,
+    pub royalty: Option<RoyaltyInfo>,
+}
+
+/// Event data emitted when the collection-level metadata is updated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMetadataUpdatedEventData {
+    #[topic]
+    pub admin: Address,
+    pub metadata: ContractMetadata,
+}
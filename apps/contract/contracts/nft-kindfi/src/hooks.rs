@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::types::StorageKey;
+
+/// Function invoked on the hook contract.
+const ON_NFT_TRANSFER: &str = "on_nft_transfer";
+
+/// Get the contract notified on transfers and burns, if any.
+pub fn get_transfer_hook(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&StorageKey::TransferHook)
+}
+
+/// Set or remove the contract notified on transfers and burns.
+pub fn set_transfer_hook(e: &Env, hook: &Option<Address>) {
+    match hook {
+        Some(hook) => e.storage().instance().set(&StorageKey::TransferHook, hook),
+        None => e.storage().instance().remove(&StorageKey::TransferHook),
+    }
+}
+
+/// Notify the hook contract that a token changed owner (`to` is None on burn).
+///
+/// The call is not wrapped in `try_invoke_contract`: if the hook fails, the
+/// transfer or burn fails too, so the hook never misses an ownership change.
+pub fn notify_transfer(e: &Env, token_id: u32, from: &Address, to: Option<&Address>) {
+    let Some(hook) = get_transfer_hook(e) else {
+        return;
+    };
+
+    e.invoke_contract::<()>(
+        &hook,
+        &Symbol::new(e, ON_NFT_TRANSFER),
+        (token_id, from.clone(), to.cloned()).into_val(e),
+    );
+}
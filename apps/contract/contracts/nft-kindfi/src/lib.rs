@@ -1,13 +1,21 @@
 #![no_std]
 
+mod airdrop;
 mod burn;
 mod errors;
 mod events;
+mod hooks;
 mod metadata;
 mod mint;
+mod provenance;
+mod royalty;
 mod types;
+mod validation;
+mod voucher;
 
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, String, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, String, Symbol, Vec,
+};
 use stellar_access::access_control::{
     accept_admin_transfer as storage_accept_admin_transfer, get_admin as storage_get_admin,
     get_role_admin as storage_get_role_admin, get_role_member as storage_get_role_member,
@@ -21,8 +29,16 @@ use stellar_macros::{has_role, only_role};
 use stellar_tokens::non_fungible::{burnable::NonFungibleBurnable, Base, NonFungibleToken};
 
 use crate::errors::Error;
-use crate::events::{MetadataUpdatedEventData};
-use crate::types::NFTMetadata;
+use crate::events::{
+    AirdropUpdatedEventData, CollectionFieldsFrozenEventData, ContractMetadataUpdatedEventData,
+    DefaultRoyaltyUpdatedEventData, MetadataUpdatedEventData, TemplateCreatedEventData,
+    TokenFieldsFrozenEventData, TokenRoyaltyUpdatedEventData, TransferHookUpdatedEventData,
+    VoucherSignerRegisteredEventData, VoucherSignerRemovedEventData,
+};
+use crate::types::{
+    AirdropConfig, ContractMetadata, FrozenFields, MetadataRevision, MintVoucher, NFTAttribute,
+    NFTMetadata, RoyaltyInfo, Tombstone,
+};
 
 // ============================================================================
 // Constants
@@ -115,8 +131,298 @@ impl KindfiNFT {
         mint::mint_with_metadata(e, &to, &nft_metadata)
     }
 
+    /// Re-issue a burned NFT under its original token ID, e.g. to a recovered account.
+    /// The transfer hook, if any, is notified as for a transfer from the last owner.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `token_id` - ID of the burned token
+    /// * `to` - Address to receive the re-issued NFT
+    /// * `nft_metadata` - Metadata of the token at burn time
+    ///
+    /// # Errors
+    /// * `Error::TokenNotBurned` - If the token has no tombstone
+    /// * `Error::TombstoneMetadataMismatch` - If the metadata hash differs from the tombstone
+    pub fn reissue_burned(
+        e: &Env,
+        caller: Address,
+        token_id: u32,
+        to: Address,
+        nft_metadata: NFTMetadata,
+    ) {
+        Self::require_admin(e, &caller);
+        burn::reissue(e, token_id, &to, &nft_metadata);
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Register an ed25519 public key allowed to sign mint vouchers.
+    ///
+    /// Vouchers signed by the key stay claimable only while `caller` keeps
+    /// the minter role.
+    ///
+    /// Requires the "minter" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Minter registering the key
+    /// * `public_key` - Ed25519 public key of the off-chain signer
+    #[only_role(caller, "minter")]
+    pub fn register_voucher_signer(e: &Env, caller: Address, public_key: BytesN<32>) {
+        voucher::set_signer(e, &public_key, &caller);
+
+        VoucherSignerRegisteredEventData {
+            public_key,
+            registered_by: caller,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Remove a voucher signer key. Unclaimed vouchers it signed become invalid.
+    ///
+    /// Requires the "minter" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Minter removing the key
+    /// * `public_key` - Ed25519 public key to remove
+    #[only_role(caller, "minter")]
+    pub fn remove_voucher_signer(e: &Env, caller: Address, public_key: BytesN<32>) {
+        if voucher::get_signer(e, &public_key).is_none() {
+            panic_with_error!(e, Error::UnknownVoucherSigner);
+        }
+        voucher::remove_signer(e, &public_key);
+
+        VoucherSignerRemovedEventData {
+            public_key,
+            removed_by: caller,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Mint an NFT from a voucher signed off-chain by a registered signer key.
+    ///
+    /// Anyone can submit the voucher; the NFT always goes to `voucher.recipient`.
+    /// The supplied metadata must hash to `voucher.metadata_hash`, so the token
+    /// gets exactly the metadata the signer approved.
+    ///
+    /// # Arguments
+    /// * `voucher` - The signed mint voucher
+    /// * `nft_metadata` - Metadata matching the voucher's metadata hash
+    /// * `signature` - Ed25519 signature over `get_voucher_digest(voucher)`
+    ///
+    /// # Returns
+    /// The token ID of the newly minted NFT
+    ///
+    /// # Errors
+    /// * `Error::UnknownVoucherSigner` - If the signer key is not registered by a current minter
+    /// * `Error::VoucherExpired` - If the voucher expiry has passed
+    /// * `Error::VoucherNonceUsed` - If the nonce was already claimed
+    /// * `Error::VoucherMetadataMismatch` - If the metadata does not match the voucher
+    pub fn claim_with_voucher(
+        e: &Env,
+        voucher: MintVoucher,
+        nft_metadata: NFTMetadata,
+        signature: BytesN<64>,
+    ) -> u32 {
+        Self::extend_instance_ttl(e);
+        voucher::claim(e, &voucher, &nft_metadata, &signature)
+    }
+
+    /// Get the digest a signer must sign for a voucher.
+    pub fn get_voucher_digest(e: &Env, voucher: MintVoucher) -> BytesN<32> {
+        voucher::voucher_digest(e, &voucher)
+    }
+
+    /// Check whether a public key is registered as a voucher signer.
+    pub fn is_voucher_signer(e: &Env, public_key: BytesN<32>) -> bool {
+        voucher::get_signer(e, &public_key).is_some()
+    }
+
+    /// Check whether a signer's voucher nonce has already been claimed.
+    pub fn is_voucher_nonce_used(e: &Env, signer: BytesN<32>, nonce: u64) -> bool {
+        voucher::is_nonce_used(e, &signer, nonce)
+    }
+
+    /// Get the SHA-256 hash of a metadata value, as used in mint vouchers.
+    pub fn hash_metadata(e: &Env, nft_metadata: NFTMetadata) -> BytesN<32> {
+        metadata::hash_metadata(e, &nft_metadata)
+    }
+
+    /// Create a metadata template that airdropped tokens reference by ID.
+    ///
+    /// Templates are stored once and cannot be changed after creation.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `template_id` - ID of the new template
+    /// * `nft_metadata` - Metadata shared by every token minted from the template
+    ///
+    /// # Errors
+    /// * `Error::TemplateAlreadyExists` - If the template ID is already in use
+    pub fn create_metadata_template(
+        e: &Env,
+        caller: Address,
+        template_id: u32,
+        nft_metadata: NFTMetadata,
+    ) {
+        Self::require_admin(e, &caller);
+
+        metadata::create_template(e, template_id, &nft_metadata);
+
+        TemplateCreatedEventData {
+            template_id,
+            metadata: nft_metadata,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get a metadata template.
+    pub fn get_metadata_template(e: &Env, template_id: u32) -> Option<NFTMetadata> {
+        metadata::get_template(e, template_id)
+    }
+
+    /// Publish the Merkle root of the airdrop allowlist.
+    ///
+    /// Each leaf is `sha256(xdr((address, template_id)))`. Publishing a root
+    /// leaves the claim window closed until `open_airdrop_claims` is called.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `merkle_root` - Root of the allowlist Merkle tree
+    pub fn set_airdrop_root(e: &Env, caller: Address, merkle_root: BytesN<32>) {
+        Self::require_admin(e, &caller);
+
+        let config = AirdropConfig {
+            merkle_root,
+            claims_open_at: 0,
+            claims_close_at: 0,
+        };
+        airdrop::set_config(e, &config);
+
+        AirdropUpdatedEventData {
+            admin: caller,
+            config,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Open the airdrop claim window.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `opens_at` - Ledger timestamp at which claims open
+    /// * `closes_at` - Ledger timestamp at which claims close
+    ///
+    /// # Errors
+    /// * `Error::AirdropNotConfigured` - If no Merkle root was published
+    /// * `Error::InvalidClaimWindow` - If `closes_at` is not after `opens_at`
+    pub fn open_airdrop_claims(e: &Env, caller: Address, opens_at: u64, closes_at: u64) {
+        Self::require_admin(e, &caller);
+        if closes_at <= opens_at {
+            panic_with_error!(e, Error::InvalidClaimWindow);
+        }
+
+        let mut config = airdrop::require_config(e);
+        config.claims_open_at = opens_at;
+        config.claims_close_at = closes_at;
+        airdrop::set_config(e, &config);
+
+        AirdropUpdatedEventData {
+            admin: caller,
+            config,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Close the airdrop claim window immediately.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    pub fn close_airdrop_claims(e: &Env, caller: Address) {
+        Self::require_admin(e, &caller);
+
+        let mut config = airdrop::require_config(e);
+        config.claims_close_at = e.ledger().timestamp();
+        if config.claims_open_at > config.claims_close_at {
+            config.claims_open_at = config.claims_close_at;
+        }
+        airdrop::set_config(e, &config);
+
+        AirdropUpdatedEventData {
+            admin: caller,
+            config,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the current airdrop configuration.
+    pub fn get_airdrop_config(e: &Env) -> Option<AirdropConfig> {
+        airdrop::get_config(e)
+    }
+
+    /// Claim an airdropped badge from the allowlist.
+    ///
+    /// Each (claimant, template_id) leaf can be claimed once.
+    ///
+    /// # Arguments
+    /// * `claimant` - Allowlisted address (must authorize; receives the NFT)
+    /// * `template_id` - Template listed for the claimant in the allowlist
+    /// * `proof` - Sibling hashes from the leaf up to the root
+    ///
+    /// # Returns
+    /// The token ID of the newly minted NFT
+    ///
+    /// # Errors
+    /// * `Error::AirdropNotConfigured` - If no Merkle root was published
+    /// * `Error::AirdropClosed` - If the claim window is not open
+    /// * `Error::AirdropAlreadyClaimed` - If the leaf was already claimed
+    /// * `Error::InvalidMerkleProof` - If the proof does not match the root
+    /// * `Error::TemplateNotFound` - If the template does not exist
+    pub fn claim(e: &Env, claimant: Address, template_id: u32, proof: Vec<BytesN<32>>) -> u32 {
+        claimant.require_auth();
+        Self::extend_instance_ttl(e);
+        airdrop::claim(e, &claimant, template_id, &proof)
+    }
+
+    /// Get the allowlist leaf for a (claimant, template_id) entry.
+    pub fn get_airdrop_leaf(e: &Env, claimant: Address, template_id: u32) -> BytesN<32> {
+        airdrop::leaf(e, &claimant, template_id)
+    }
+
+    /// Check whether a (claimant, template_id) entry has been claimed.
+    pub fn is_airdrop_claimed(e: &Env, claimant: Address, template_id: u32) -> bool {
+        airdrop::is_claimed(e, &airdrop::leaf(e, &claimant, template_id))
+    }
+
+    /// Get the template a token was minted from, if any.
+    pub fn get_token_template(e: &Env, token_id: u32) -> Option<u32> {
+        metadata::get_token_template(e, token_id)
+    }
+
     /// Update the metadata for an existing NFT.
     ///
+    /// The replaced version is recorded in the token's provenance history.
+    ///
     /// Requires the "metadata_manager" role.
     ///
     /// # Arguments
@@ -126,19 +432,15 @@ impl KindfiNFT {
     ///
     /// # Errors
     /// * `Error::Unauthorized` - If caller doesn't have metadata_manager role
+    /// * `Error::FieldFrozen` - If the update changes a frozen field
     pub fn update_metadata(e: &Env, caller: Address, token_id: u32, nft_metadata: NFTMetadata) {
-        // Verify caller has metadata_manager role (using full name with Symbol::new)
-        let role = Symbol::new(e, METADATA_MANAGER_ROLE);
-        if storage_has_role(e, &caller, &role).is_none() {
-            panic_with_error!(e, Error::Unauthorized);
-        }
-        caller.require_auth();
+        Self::require_metadata_manager(e, &caller);
 
         // Verify token exists by checking owner (will panic if not found)
         let _ = Base::owner_of(e, token_id);
 
         // Update metadata
-        metadata::set_metadata(e, token_id, &nft_metadata);
+        metadata::update_metadata(e, token_id, &nft_metadata, &caller);
 
         // Emit metadata updated event
         MetadataUpdatedEventData {
@@ -149,6 +451,165 @@ impl KindfiNFT {
         Self::extend_instance_ttl(e);
     }
 
+    /// Add or replace a single attribute on an existing NFT.
+    ///
+    /// If the token already has an attribute with the same `trait_type`,
+    /// it is replaced (this is how an attribute is updated); otherwise the
+    /// attribute is appended.
+    ///
+    /// Requires the "metadata_manager" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have metadata_manager role)
+    /// * `token_id` - ID of the token to update
+    /// * `trait_type` - Trait this attribute represents (e.g., "level")
+    /// * `value` - Value of the attribute (e.g., "gold")
+    /// * `display_type` - Optional display type hint for UIs
+    /// * `max_value` - Optional maximum value for numeric traits
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - If caller doesn't have metadata_manager role
+    /// * `Error::MetadataNotFound` - If the token has no metadata
+    /// * `Error::FieldFrozen` - If the token's attributes are frozen
+    pub fn set_attribute(
+        e: &Env,
+        caller: Address,
+        token_id: u32,
+        trait_type: String,
+        value: String,
+        display_type: Option<String>,
+        max_value: Option<String>,
+    ) {
+        Self::require_metadata_manager(e, &caller);
+        let _ = Base::owner_of(e, token_id);
+
+        let attribute = NFTAttribute {
+            trait_type,
+            value,
+            display_type,
+            max_value,
+        };
+        metadata::set_attribute(e, token_id, &attribute, &caller);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Add or replace several attributes on an existing NFT in one update.
+    ///
+    /// Each attribute is applied as in `set_attribute`, but the whole batch
+    /// succeeds or fails together and is recorded as a single provenance
+    /// revision. One `attribute_set` event is emitted per attribute.
+    ///
+    /// Requires the "metadata_manager" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have metadata_manager role)
+    /// * `token_id` - ID of the token to update
+    /// * `attributes` - Attributes to set (each trait type at most once)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - If caller doesn't have metadata_manager role
+    /// * `Error::MetadataNotFound` - If the token has no metadata
+    /// * `Error::DuplicateTraitType` - If the batch repeats a trait type
+    /// * `Error::TooManyAttributes` - If the token would exceed the attribute limit
+    /// * `Error::FieldFrozen` - If the token's attributes are frozen
+    pub fn set_attributes(e: &Env, caller: Address, token_id: u32, attributes: Vec<NFTAttribute>) {
+        Self::require_metadata_manager(e, &caller);
+        let _ = Base::owner_of(e, token_id);
+
+        metadata::set_attributes(e, token_id, &attributes, &caller);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Remove a single attribute from an existing NFT.
+    ///
+    /// Requires the "metadata_manager" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have metadata_manager role)
+    /// * `token_id` - ID of the token to update
+    /// * `trait_type` - Trait type of the attribute to remove
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - If caller doesn't have metadata_manager role
+    /// * `Error::MetadataNotFound` - If the token has no metadata
+    /// * `Error::AttributeNotFound` - If the token has no attribute with that trait type
+    /// * `Error::FieldFrozen` - If the token's attributes are frozen
+    pub fn remove_attribute(e: &Env, caller: Address, token_id: u32, trait_type: String) {
+        Self::require_metadata_manager(e, &caller);
+        let _ = Base::owner_of(e, token_id);
+
+        metadata::remove_attribute(e, token_id, &trait_type, &caller);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Permanently freeze metadata fields of a single token.
+    ///
+    /// Fields already frozen stay frozen; there is no way to unfreeze.
+    ///
+    /// Requires the "metadata_manager" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the freeze (must have metadata_manager role)
+    /// * `token_id` - ID of the token to freeze
+    /// * `fields` - Fields to freeze (fields set to `false` are left unchanged)
+    pub fn freeze_token_metadata(e: &Env, caller: Address, token_id: u32, fields: FrozenFields) {
+        Self::require_metadata_manager(e, &caller);
+        let _ = Base::owner_of(e, token_id);
+
+        let fields = provenance::freeze_token_fields(e, token_id, &fields);
+
+        TokenFieldsFrozenEventData { token_id, fields }.publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Permanently freeze metadata fields for every token in the collection.
+    ///
+    /// Fields already frozen stay frozen; there is no way to unfreeze.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `fields` - Fields to freeze (fields set to `false` are left unchanged)
+    pub fn freeze_collection_metadata(e: &Env, caller: Address, fields: FrozenFields) {
+        Self::require_admin(e, &caller);
+
+        let fields = provenance::freeze_collection_fields(e, &fields);
+
+        CollectionFieldsFrozenEventData {
+            admin: caller,
+            fields,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the effective frozen fields of a token (per-token and collection-wide).
+    pub fn get_frozen_fields(e: &Env, token_id: u32) -> FrozenFields {
+        provenance::get_frozen_fields(e, token_id)
+    }
+
+    /// Get the current metadata revision of a token.
+    ///
+    /// # Returns
+    /// The number of times the token's metadata has been changed since mint
+    pub fn get_metadata_revision(e: &Env, token_id: u32) -> u32 {
+        provenance::get_revision(e, token_id)
+    }
+
+    /// Get the retained provenance history of a token, oldest first.
+    ///
+    /// Each entry holds the hash of a replaced version. Only the most recent
+    /// `MAX_METADATA_HISTORY` entries are kept.
+    pub fn get_metadata_history(e: &Env, token_id: u32) -> Vec<MetadataRevision> {
+        provenance::get_history(e, token_id)
+    }
+
     /// Get the custom metadata for a specific token.
     ///
     /// # Arguments
@@ -171,12 +632,228 @@ impl KindfiNFT {
         mint::get_token_counter(e)
     }
 
-    /// Get the metadata_manager role symbol.
+    /// Get the number of NFTs currently burned.
+    pub fn burned_count(e: &Env) -> u32 {
+        burn::get_burned_count(e)
+    }
+
+    /// Get the number of NFTs in circulation (minted minus burned).
+    pub fn circulating_supply(e: &Env) -> u32 {
+        mint::get_token_counter(e).saturating_sub(burn::get_burned_count(e))
+    }
+
+    /// Get the tombstone of a burned NFT.
+    ///
+    /// # Returns
+    /// The last owner, burn ledger and metadata hash, or None if the token is not burned
+    pub fn get_tombstone(e: &Env, token_id: u32) -> Option<Tombstone> {
+        burn::get_tombstone(e, token_id)
+    }
+
+    /// Set the collection-level metadata (description, image, external link).
+    ///
+    /// Complements the name, symbol and base URI set at construction.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `contract_metadata` - New collection-level metadata
+    pub fn set_contract_metadata(e: &Env, caller: Address, contract_metadata: ContractMetadata) {
+        Self::require_admin(e, &caller);
+
+        metadata::set_contract_metadata(e, &contract_metadata);
+
+        ContractMetadataUpdatedEventData {
+            admin: caller,
+            metadata: contract_metadata,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the collection-level metadata.
+    pub fn contract_metadata(e: &Env) -> Option<ContractMetadata> {
+        metadata::get_contract_metadata(e)
+    }
+
+    /// Set the collection-wide default royalty.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `receiver` - Address receiving royalties (e.g. the campaign)
+    /// * `basis_points` - Royalty rate in basis points (10000 = 100%)
+    ///
+    /// # Errors
+    /// * `Error::InvalidRoyalty` - If basis_points exceeds 10000
+    pub fn set_default_royalty(e: &Env, caller: Address, receiver: Address, basis_points: u32) {
+        Self::require_admin(e, &caller);
+
+        let royalty = RoyaltyInfo {
+            receiver,
+            basis_points,
+        };
+        royalty::require_valid_royalty(e, &royalty);
+        royalty::set_default_royalty(e, &Some(royalty.clone()));
+
+        DefaultRoyaltyUpdatedEventData {
+            admin: caller,
+            royalty: Some(royalty),
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Remove the collection-wide default royalty.
+    ///
+    /// Only admin can call this function.
+    pub fn remove_default_royalty(e: &Env, caller: Address) {
+        Self::require_admin(e, &caller);
+
+        royalty::set_default_royalty(e, &None);
+
+        DefaultRoyaltyUpdatedEventData {
+            admin: caller,
+            royalty: None,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set a royalty override for a single token.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `token_id` - ID of the token
+    /// * `receiver` - Address receiving royalties for this token
+    /// * `basis_points` - Royalty rate in basis points (10000 = 100%)
+    ///
+    /// # Errors
+    /// * `Error::InvalidRoyalty` - If basis_points exceeds 10000
+    pub fn set_token_royalty(
+        e: &Env,
+        caller: Address,
+        token_id: u32,
+        receiver: Address,
+        basis_points: u32,
+    ) {
+        Self::require_admin(e, &caller);
+        let _ = Base::owner_of(e, token_id);
+
+        let royalty = RoyaltyInfo {
+            receiver,
+            basis_points,
+        };
+        royalty::require_valid_royalty(e, &royalty);
+        royalty::set_token_royalty(e, token_id, &Some(royalty.clone()));
+
+        TokenRoyaltyUpdatedEventData {
+            token_id,
+            royalty: Some(royalty),
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Remove the royalty override of a single token, falling back to the default.
+    ///
+    /// Only admin can call this function. The token must exist.
+    pub fn remove_token_royalty(e: &Env, caller: Address, token_id: u32) {
+        Self::require_admin(e, &caller);
+        let _ = Base::owner_of(e, token_id);
+
+        royalty::set_token_royalty(e, token_id, &None);
+
+        TokenRoyaltyUpdatedEventData {
+            token_id,
+            royalty: None,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the royalty owed on a sale of a token (EIP-2981 style).
+    ///
+    /// # Arguments
+    /// * `token_id` - ID of the token being sold
+    /// * `sale_price` - Sale price in the payment asset's smallest unit
+    ///
+    /// # Returns
+    /// The royalty receiver and amount, or None if no royalty is configured
+    ///
+    /// # Errors
+    /// * `Error::InvalidSalePrice` - If the sale price is negative or too large
+    pub fn royalty_info(e: &Env, token_id: u32, sale_price: i128) -> Option<(Address, i128)> {
+        royalty::royalty_info(e, token_id, sale_price)
+    }
+
+    /// Get the collection-wide default royalty.
+    pub fn get_default_royalty(e: &Env) -> Option<RoyaltyInfo> {
+        royalty::get_default_royalty(e)
+    }
+
+    /// Set or remove the contract notified when a token is transferred or burned.
+    ///
+    /// The hook must expose `on_nft_transfer(token_id: u32, from: Address, to: Option<Address>)`.
+    /// A failing hook reverts the transfer or burn.
+    ///
+    /// Only admin can call this function.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `hook` - Hook contract (e.g. the Reputation contract), or None to remove it
+    pub fn set_transfer_hook(e: &Env, caller: Address, hook: Option<Address>) {
+        Self::require_admin(e, &caller);
+
+        hooks::set_transfer_hook(e, &hook);
+
+        TransferHookUpdatedEventData {
+            admin: caller,
+            hook,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the contract notified when a token is transferred or burned.
+    pub fn get_transfer_hook(e: &Env) -> Option<Address> {
+        hooks::get_transfer_hook(e)
+    }
+
     /// Helper function since the role name exceeds symbol_short! limit.
     pub fn metadata_manager_role(e: &Env) -> Symbol {
         Symbol::new(e, METADATA_MANAGER_ROLE)
     }
 
+    /// Verify the caller is the contract admin and authorized the call.
+    fn require_admin(e: &Env, caller: &Address) {
+        let admin = storage_get_admin(e);
+        if admin.is_none() || admin.unwrap() != *caller {
+            panic_with_error!(e, Error::Unauthorized);
+        }
+        caller.require_auth();
+    }
+
+    /// Verify the caller holds the metadata_manager role and authorized the call.
+    /// Uses Symbol::new since the role name exceeds the symbol_short! limit.
+    fn require_metadata_manager(e: &Env, caller: &Address) {
+        let role = Symbol::new(e, METADATA_MANAGER_ROLE);
+        if storage_has_role(e, caller, &role).is_none() {
+            panic_with_error!(e, Error::Unauthorized);
+        }
+        caller.require_auth();
+    }
+
     /// Extend the TTL of instance storage.
     /// Called internally during state-changing operations.
     fn extend_instance_ttl(e: &Env) {
@@ -202,11 +879,13 @@ impl NonFungibleToken for KindfiNFT {
 
     fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
         Self::ContractType::transfer(e, &from, &to, token_id);
+        hooks::notify_transfer(e, token_id, &from, Some(&to));
         KindfiNFT::extend_instance_ttl(e);
     }
 
     fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
         Self::ContractType::transfer_from(e, &spender, &from, &to, token_id);
+        hooks::notify_transfer(e, token_id, &from, Some(&to));
         KindfiNFT::extend_instance_ttl(e);
     }
 
@@ -1,36 +1,65 @@
-use soroban_sdk::Env;
+use soroban_sdk::{panic_with_error, xdr::ToXdr, Address, BytesN, Env, String, Vec};
 
-use crate::events::MetadataSetEventData;
-use crate::types::{NFTMetadata, StorageKey};
+use crate::errors::Error;
+use crate::events::{AttributeRemovedEventData, AttributeSetEventData, MetadataSetEventData};
+use crate::provenance;
+use crate::types::{ContractMetadata, NFTAttribute, NFTMetadata, StorageKey};
+use crate::validation::{self, MAX_ATTRIBUTES};
 
 /// TTL constants for metadata storage (30 days in ledgers)
 const DAY_IN_LEDGERS: u32 = 17280;
 const METADATA_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const METADATA_TTL_THRESHOLD: u32 = METADATA_TTL_AMOUNT - DAY_IN_LEDGERS;
 
-/// Validates that the metadata fields meet minimum requirements.
-/// Panics if validation fails.
-fn require_valid_metadata(metadata: &NFTMetadata) {
-    assert!(metadata.name.len() > 0, "name cannot be empty");
-    assert!(metadata.image_uri.len() > 0, "image_uri cannot be empty");
+/// Find the position of the attribute with the given trait_type.
+fn find_attribute(attributes: &Vec<NFTAttribute>, trait_type: &String) -> Option<u32> {
+    attributes
+        .iter()
+        .position(|attr| attr.trait_type == *trait_type)
+        .map(|index| index as u32)
 }
 
-/// Store metadata for a specific token ID.
-/// Uses persistent storage with automatic TTL extension.
-pub fn set_metadata(e: &Env, token_id: u32, metadata: &NFTMetadata) {
-    require_valid_metadata(metadata);
+/// Write metadata to persistent storage and extend its TTL.
+fn write_metadata(e: &Env, token_id: u32, metadata: &NFTMetadata) {
     let key = StorageKey::TokenMetadata(token_id);
     e.storage().persistent().set(&key, metadata);
     e.storage()
         .persistent()
         .extend_ttl(&key, METADATA_TTL_THRESHOLD, METADATA_TTL_AMOUNT);
+}
+
+/// Store metadata for a specific token ID.
+/// Validates the metadata and uses persistent storage with automatic TTL extension.
+pub fn set_metadata(e: &Env, token_id: u32, metadata: &NFTMetadata) {
+    validation::require_valid_metadata(e, metadata);
+    write_metadata(e, token_id, metadata);
     MetadataSetEventData {
         token_id,
         metadata: metadata.clone(),
     }.publish(e);
 }
 
+/// Replace the metadata of an existing token.
+/// Rejects changes to frozen fields and records the replaced version in the
+/// token's provenance history.
+pub fn update_metadata(e: &Env, token_id: u32, metadata: &NFTMetadata, updated_by: &Address) {
+    let Some(current) = get_metadata(e, token_id) else {
+        panic_with_error!(e, Error::MetadataNotFound);
+    };
+    provenance::require_unfrozen(e, token_id, &current, metadata);
+
+    set_metadata(e, token_id, metadata);
+    provenance::record_revision(e, token_id, &current, updated_by);
+}
+
+/// Compute the SHA-256 hash of the XDR encoding of a metadata value.
+pub fn hash_metadata(e: &Env, metadata: &NFTMetadata) -> BytesN<32> {
+    e.crypto().sha256(&metadata.clone().to_xdr(e)).to_bytes()
+}
+
 /// Retrieve metadata for a specific token ID.
+/// Tokens minted from a template resolve to the template until their
+/// metadata is changed, at which point they get their own copy.
 /// Returns None if no metadata exists for the token.
 pub fn get_metadata(e: &Env, token_id: u32) -> Option<NFTMetadata> {
     let key = StorageKey::TokenMetadata(token_id);
@@ -40,15 +69,163 @@ pub fn get_metadata(e: &Env, token_id: u32) -> Option<NFTMetadata> {
         e.storage()
             .persistent()
             .extend_ttl(&key, METADATA_TTL_THRESHOLD, METADATA_TTL_AMOUNT);
+        return metadata;
+    }
+
+    get_token_template(e, token_id).and_then(|template_id| get_template(e, template_id))
+}
+
+// ============================================================================
+// Collection Metadata
+// ============================================================================
+
+/// Get the collection-level metadata.
+pub fn get_contract_metadata(e: &Env) -> Option<ContractMetadata> {
+    e.storage().instance().get(&StorageKey::ContractMetadata)
+}
+
+/// Validate and store the collection-level metadata.
+pub fn set_contract_metadata(e: &Env, metadata: &ContractMetadata) {
+    validation::require_valid_contract_metadata(e, metadata);
+    e.storage()
+        .instance()
+        .set(&StorageKey::ContractMetadata, metadata);
+}
+
+// ============================================================================
+// Templates
+// ============================================================================
+
+/// Store a new metadata template. Templates are immutable once created so
+/// tokens referencing them cannot be rewritten.
+pub fn create_template(e: &Env, template_id: u32, metadata: &NFTMetadata) {
+    let key = StorageKey::MetadataTemplate(template_id);
+    if e.storage().persistent().has(&key) {
+        panic_with_error!(e, Error::TemplateAlreadyExists);
     }
+    validation::require_valid_metadata(e, metadata);
 
+    e.storage().persistent().set(&key, metadata);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, METADATA_TTL_THRESHOLD, METADATA_TTL_AMOUNT);
+}
+
+/// Retrieve a metadata template.
+pub fn get_template(e: &Env, template_id: u32) -> Option<NFTMetadata> {
+    let key = StorageKey::MetadataTemplate(template_id);
+    let metadata: Option<NFTMetadata> = e.storage().persistent().get(&key);
+    if metadata.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, METADATA_TTL_THRESHOLD, METADATA_TTL_AMOUNT);
+    }
     metadata
 }
 
-/// Remove metadata for a specific token ID.
+/// Reference a template as the metadata of a token.
+pub fn set_token_template(e: &Env, token_id: u32, template_id: u32) {
+    let key = StorageKey::TokenTemplate(token_id);
+    e.storage().persistent().set(&key, &template_id);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, METADATA_TTL_THRESHOLD, METADATA_TTL_AMOUNT);
+}
+
+/// Get the template a token was minted from, if any.
+pub fn get_token_template(e: &Env, token_id: u32) -> Option<u32> {
+    let key = StorageKey::TokenTemplate(token_id);
+    let template_id: Option<u32> = e.storage().persistent().get(&key);
+    if template_id.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, METADATA_TTL_THRESHOLD, METADATA_TTL_AMOUNT);
+    }
+    template_id
+}
+
+/// Add or replace a single attribute on a token.
+/// An existing attribute with the same trait_type is replaced in place: this
+/// is how attributes are updated, and the token never ends up with duplicate
+/// trait types.
+pub fn set_attribute(e: &Env, token_id: u32, attribute: &NFTAttribute, updated_by: &Address) {
+    let attributes = Vec::from_array(e, [attribute.clone()]);
+    set_attributes(e, token_id, &attributes, updated_by);
+}
+
+/// Add or replace several attributes on a token in one update.
+/// Each attribute is applied as in `set_attribute`, but all of them are
+/// written at once with a single provenance revision. The list itself must
+/// not repeat a trait_type. An empty list leaves the token unchanged.
+pub fn set_attributes(
+    e: &Env,
+    token_id: u32,
+    attributes: &Vec<NFTAttribute>,
+    updated_by: &Address,
+) {
+    let Some(mut metadata) = get_metadata(e, token_id) else {
+        panic_with_error!(e, Error::MetadataNotFound);
+    };
+    provenance::require_attributes_unfrozen(e, token_id);
+    validation::require_valid_attributes(e, attributes);
+    if attributes.is_empty() {
+        return;
+    }
+    let previous = metadata.clone();
+
+    for attribute in attributes.iter() {
+        match find_attribute(&metadata.attributes, &attribute.trait_type) {
+            Some(index) => metadata.attributes.set(index, attribute),
+            None => {
+                if metadata.attributes.len() >= MAX_ATTRIBUTES {
+                    panic_with_error!(e, Error::TooManyAttributes);
+                }
+                metadata.attributes.push_back(attribute);
+            }
+        }
+    }
+
+    write_metadata(e, token_id, &metadata);
+    provenance::record_revision(e, token_id, &previous, updated_by);
+
+    for attribute in attributes.iter() {
+        AttributeSetEventData {
+            token_id,
+            trait_type: attribute.trait_type.clone(),
+            attribute,
+        }.publish(e);
+    }
+}
+
+/// Remove the attribute with the given trait_type from a token.
+pub fn remove_attribute(e: &Env, token_id: u32, trait_type: &String, updated_by: &Address) {
+    let Some(mut metadata) = get_metadata(e, token_id) else {
+        panic_with_error!(e, Error::MetadataNotFound);
+    };
+    provenance::require_attributes_unfrozen(e, token_id);
+    let previous = metadata.clone();
+
+    let Some(index) = find_attribute(&metadata.attributes, trait_type) else {
+        panic_with_error!(e, Error::AttributeNotFound);
+    };
+    metadata.attributes.remove(index);
+
+    write_metadata(e, token_id, &metadata);
+    provenance::record_revision(e, token_id, &previous, updated_by);
+
+    AttributeRemovedEventData {
+        token_id,
+        trait_type: trait_type.clone(),
+    }.publish(e);
+}
+
+/// Remove metadata for a specific token ID, including its template reference.
 /// Called when burning an NFT.
 pub fn remove_metadata(e: &Env, token_id: u32) {
-    let key = StorageKey::TokenMetadata(token_id);
-    e.storage().persistent().remove(&key);
+    e.storage()
+        .persistent()
+        .remove(&StorageKey::TokenMetadata(token_id));
+    e.storage()
+        .persistent()
+        .remove(&StorageKey::TokenTemplate(token_id));
 }
-
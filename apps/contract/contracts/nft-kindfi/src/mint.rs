@@ -48,3 +48,24 @@ pub fn mint_with_metadata(e: &Env, to: &Address, nft_metadata: &NFTMetadata) ->
 
     token_id
 }
+
+/// Mint a new NFT whose metadata references a stored template.
+/// The template is not copied; the token resolves to it until its metadata
+/// is changed. Returns the minted token ID.
+pub fn mint_from_template(e: &Env, to: &Address, template_id: u32) -> u32 {
+    let Some(template) = metadata::get_template(e, template_id) else {
+        panic_with_error!(e, Error::TemplateNotFound);
+    };
+
+    let token_id = increment_token_counter(e);
+    Base::mint(e, to, token_id);
+    metadata::set_token_template(e, token_id, template_id);
+
+    MintedEventData {
+        token_id,
+        to: to.clone(),
+        metadata: template,
+    }.publish(e);
+
+    token_id
+}
@@ -0,0 +1,155 @@
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+use crate::errors::Error;
+use crate::metadata;
+use crate::types::{FrozenFields, MetadataRevision, NFTMetadata, StorageKey};
+
+/// TTL constants for provenance storage (30 days in ledgers)
+const DAY_IN_LEDGERS: u32 = 17280;
+const PROVENANCE_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PROVENANCE_TTL_THRESHOLD: u32 = PROVENANCE_TTL_AMOUNT - DAY_IN_LEDGERS;
+
+/// Maximum number of replaced versions kept per token.
+/// Older entries are dropped once the limit is reached; the revision
+/// counter keeps counting so gaps remain visible.
+pub const MAX_METADATA_HISTORY: u32 = 20;
+
+// ============================================================================
+// Freeze Flags
+// ============================================================================
+
+/// Combine two sets of frozen fields. A field frozen in either set stays frozen.
+fn merge_frozen_fields(a: &FrozenFields, b: &FrozenFields) -> FrozenFields {
+    FrozenFields {
+        name: a.name || b.name,
+        description: a.description || b.description,
+        image_uri: a.image_uri || b.image_uri,
+        external_url: a.external_url || b.external_url,
+        attributes: a.attributes || b.attributes,
+    }
+}
+
+/// Get the fields frozen for every token in the collection.
+pub fn get_collection_frozen_fields(e: &Env) -> FrozenFields {
+    e.storage()
+        .instance()
+        .get(&StorageKey::CollectionFrozenFields)
+        .unwrap_or_default()
+}
+
+/// Freeze additional fields for the whole collection.
+/// Returns the resulting collection-wide frozen fields.
+pub fn freeze_collection_fields(e: &Env, fields: &FrozenFields) -> FrozenFields {
+    let merged = merge_frozen_fields(&get_collection_frozen_fields(e), fields);
+    e.storage()
+        .instance()
+        .set(&StorageKey::CollectionFrozenFields, &merged);
+    merged
+}
+
+/// Get the fields frozen for a single token, ignoring collection-wide flags.
+fn get_token_frozen_fields(e: &Env, token_id: u32) -> FrozenFields {
+    let key = StorageKey::TokenFrozenFields(token_id);
+    let fields: Option<FrozenFields> = e.storage().persistent().get(&key);
+    if fields.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PROVENANCE_TTL_THRESHOLD, PROVENANCE_TTL_AMOUNT);
+    }
+    fields.unwrap_or_default()
+}
+
+/// Freeze additional fields for a single token.
+/// Returns the resulting per-token frozen fields.
+pub fn freeze_token_fields(e: &Env, token_id: u32, fields: &FrozenFields) -> FrozenFields {
+    let key = StorageKey::TokenFrozenFields(token_id);
+    let merged = merge_frozen_fields(&get_token_frozen_fields(e, token_id), fields);
+    e.storage().persistent().set(&key, &merged);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PROVENANCE_TTL_THRESHOLD, PROVENANCE_TTL_AMOUNT);
+    merged
+}
+
+/// Get the effective frozen fields for a token (per-token and collection-wide).
+pub fn get_frozen_fields(e: &Env, token_id: u32) -> FrozenFields {
+    merge_frozen_fields(
+        &get_collection_frozen_fields(e),
+        &get_token_frozen_fields(e, token_id),
+    )
+}
+
+/// Ensure replacing `current` with `new` does not change any frozen field.
+pub fn require_unfrozen(e: &Env, token_id: u32, current: &NFTMetadata, new: &NFTMetadata) {
+    let frozen = get_frozen_fields(e, token_id);
+    if (frozen.name && current.name != new.name)
+        || (frozen.description && current.description != new.description)
+        || (frozen.image_uri && current.image_uri != new.image_uri)
+        || (frozen.external_url && current.external_url != new.external_url)
+        || (frozen.attributes && current.attributes != new.attributes)
+    {
+        panic_with_error!(e, Error::FieldFrozen);
+    }
+}
+
+/// Ensure the attributes of a token are not frozen.
+pub fn require_attributes_unfrozen(e: &Env, token_id: u32) {
+    if get_frozen_fields(e, token_id).attributes {
+        panic_with_error!(e, Error::FieldFrozen);
+    }
+}
+
+// ============================================================================
+// Revision History
+// ============================================================================
+
+/// Get the current metadata revision of a token (0 = metadata as minted).
+pub fn get_revision(e: &Env, token_id: u32) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&StorageKey::TokenRevision(token_id))
+        .unwrap_or(0)
+}
+
+/// Get the retained history of replaced metadata versions, oldest first.
+pub fn get_history(e: &Env, token_id: u32) -> Vec<MetadataRevision> {
+    let key = StorageKey::TokenHistory(token_id);
+    let history: Option<Vec<MetadataRevision>> = e.storage().persistent().get(&key);
+    match history {
+        Some(history) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, PROVENANCE_TTL_THRESHOLD, PROVENANCE_TTL_AMOUNT);
+            history
+        }
+        None => Vec::new(e),
+    }
+}
+
+/// Record that `previous` has been replaced and bump the revision counter.
+pub fn record_revision(e: &Env, token_id: u32, previous: &NFTMetadata, updated_by: &Address) {
+    let revision = get_revision(e, token_id);
+
+    let mut history = get_history(e, token_id);
+    history.push_back(MetadataRevision {
+        revision,
+        metadata_hash: metadata::hash_metadata(e, previous),
+        updated_by: updated_by.clone(),
+        replaced_at_ledger: e.ledger().sequence(),
+    });
+    while history.len() > MAX_METADATA_HISTORY {
+        history.pop_front();
+    }
+
+    let history_key = StorageKey::TokenHistory(token_id);
+    e.storage().persistent().set(&history_key, &history);
+    e.storage()
+        .persistent()
+        .extend_ttl(&history_key, PROVENANCE_TTL_THRESHOLD, PROVENANCE_TTL_AMOUNT);
+
+    let revision_key = StorageKey::TokenRevision(token_id);
+    e.storage().persistent().set(&revision_key, &(revision + 1));
+    e.storage()
+        .persistent()
+        .extend_ttl(&revision_key, PROVENANCE_TTL_THRESHOLD, PROVENANCE_TTL_AMOUNT);
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::errors::Error;
+use crate::types::{RoyaltyInfo, StorageKey};
+
+/// TTL constants for per-token royalty storage (30 days in ledgers)
+const DAY_IN_LEDGERS: u32 = 17280;
+const ROYALTY_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const ROYALTY_TTL_THRESHOLD: u32 = ROYALTY_TTL_AMOUNT - DAY_IN_LEDGERS;
+
+/// Basis points denominator (10000 = 100%).
+pub const MAX_ROYALTY_BASIS_POINTS: u32 = 10_000;
+
+/// Ensure a royalty rate does not exceed 100%.
+pub fn require_valid_royalty(e: &Env, royalty: &RoyaltyInfo) {
+    if royalty.basis_points > MAX_ROYALTY_BASIS_POINTS {
+        panic_with_error!(e, Error::InvalidRoyalty);
+    }
+}
+
+/// Get the collection-wide default royalty.
+pub fn get_default_royalty(e: &Env) -> Option<RoyaltyInfo> {
+    e.storage().instance().get(&StorageKey::DefaultRoyalty)
+}
+
+/// Set or remove the collection-wide default royalty.
+pub fn set_default_royalty(e: &Env, royalty: &Option<RoyaltyInfo>) {
+    match royalty {
+        Some(royalty) => e.storage().instance().set(&StorageKey::DefaultRoyalty, royalty),
+        None => e.storage().instance().remove(&StorageKey::DefaultRoyalty),
+    }
+}
+
+/// Get the royalty override of a single token, ignoring the default.
+pub fn get_token_royalty(e: &Env, token_id: u32) -> Option<RoyaltyInfo> {
+    let key = StorageKey::TokenRoyalty(token_id);
+    let royalty: Option<RoyaltyInfo> = e.storage().persistent().get(&key);
+    if royalty.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, ROYALTY_TTL_THRESHOLD, ROYALTY_TTL_AMOUNT);
+    }
+    royalty
+}
+
+/// Set or remove the royalty override of a single token.
+pub fn set_token_royalty(e: &Env, token_id: u32, royalty: &Option<RoyaltyInfo>) {
+    let key = StorageKey::TokenRoyalty(token_id);
+    match royalty {
+        Some(royalty) => {
+            e.storage().persistent().set(&key, royalty);
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, ROYALTY_TTL_THRESHOLD, ROYALTY_TTL_AMOUNT);
+        }
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+/// Compute the royalty owed on a sale, EIP-2981 style.
+/// The per-token override takes precedence over the collection default.
+/// Returns None when no royalty is configured for the token.
+pub fn royalty_info(e: &Env, token_id: u32, sale_price: i128) -> Option<(Address, i128)> {
+    if sale_price < 0 {
+        panic_with_error!(e, Error::InvalidSalePrice);
+    }
+
+    let royalty = get_token_royalty(e, token_id).or_else(|| get_default_royalty(e))?;
+
+    let Some(amount) = sale_price
+        .checked_mul(i128::from(royalty.basis_points))
+        .map(|value| value / i128::from(MAX_ROYALTY_BASIS_POINTS))
+    else {
+        panic_with_error!(e, Error::InvalidSalePrice);
+    };
+
+    Some((royalty.receiver, amount))
+}
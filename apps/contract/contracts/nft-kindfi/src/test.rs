@@ -1,13 +1,14 @@
 #![cfg(test)]
 extern crate std;
 
+use ed25519_dalek::{Signer, SigningKey};
 use soroban_sdk::{
-    symbol_short,
-    testutils::Address as _,
-    Address, Env, String,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env, String, Vec,
 };
 
-use crate::types::{NFTAttribute, NFTMetadata};
+use crate::types::{ContractMetadata, FrozenFields, MintVoucher, NFTAttribute, NFTMetadata};
 use crate::{KindfiNFT, KindfiNFTClient};
 
 /// Helper to create a test environment with an initialized contract.
@@ -251,6 +252,855 @@ fn test_get_metadata_nonexistent_token() {
     assert!(metadata.is_none());
 }
 
+#[test]
+fn test_set_attribute_replaces_existing_trait() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.set_attribute(
+        &metadata_manager,
+        &token_id,
+        &String::from_str(&test.env, "level"),
+        &String::from_str(&test.env, "gold"),
+        &Some(String::from_str(&test.env, "string")),
+        &None,
+    );
+
+    // Level is replaced in place, other attributes and fields are untouched
+    let stored = test.client.get_metadata(&token_id).unwrap();
+    assert_eq!(stored.name, metadata.name);
+    assert_eq!(stored.attributes.len(), 2);
+    let level = stored.attributes.get(0).unwrap();
+    assert_eq!(level.trait_type, String::from_str(&test.env, "level"));
+    assert_eq!(level.value, String::from_str(&test.env, "gold"));
+}
+
+#[test]
+fn test_set_attribute_appends_new_trait() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.set_attribute(
+        &metadata_manager,
+        &token_id,
+        &String::from_str(&test.env, "points"),
+        &String::from_str(&test.env, "250"),
+        &Some(String::from_str(&test.env, "number")),
+        &None,
+    );
+
+    let stored = test.client.get_metadata(&token_id).unwrap();
+    assert_eq!(stored.attributes.len(), 3);
+    let points = stored.attributes.get(2).unwrap();
+    assert_eq!(points.trait_type, String::from_str(&test.env, "points"));
+    assert_eq!(points.value, String::from_str(&test.env, "250"));
+}
+
+#[test]
+fn test_remove_attribute() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.remove_attribute(
+        &metadata_manager,
+        &token_id,
+        &String::from_str(&test.env, "badge"),
+    );
+
+    let stored = test.client.get_metadata(&token_id).unwrap();
+    assert_eq!(stored.attributes.len(), 1);
+    assert_eq!(
+        stored.attributes.get(0).unwrap().trait_type,
+        String::from_str(&test.env, "level")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #307)")] // AttributeNotFound
+fn test_remove_missing_attribute_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.remove_attribute(
+        &metadata_manager,
+        &token_id,
+        &String::from_str(&test.env, "rarity"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")] // Unauthorized
+fn test_set_attribute_without_role_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.set_attribute(
+        &minter,
+        &token_id,
+        &String::from_str(&test.env, "level"),
+        &String::from_str(&test.env, "gold"),
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn test_set_attributes_updates_in_one_revision() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+    let revision = test.client.get_metadata_revision(&token_id);
+
+    let attributes = Vec::from_array(
+        &test.env,
+        [
+            test.attribute("level", "gold", Some("string")),
+            test.attribute("points", "250", Some("number")),
+        ],
+    );
+    test.client
+        .set_attributes(&metadata_manager, &token_id, &attributes);
+
+    // Level is replaced in place, points is appended, in a single revision
+    let stored = test.client.get_metadata(&token_id).unwrap();
+    assert_eq!(stored.attributes.len(), 3);
+    assert_eq!(
+        stored.attributes.get(0).unwrap(),
+        attributes.get(0).unwrap()
+    );
+    assert_eq!(
+        stored.attributes.get(2).unwrap(),
+        attributes.get(1).unwrap()
+    );
+    assert_eq!(test.client.get_metadata_revision(&token_id), revision + 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #306)")] // DuplicateTraitType
+fn test_set_attributes_with_duplicate_trait_types_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    let attributes = Vec::from_array(
+        &test.env,
+        [
+            test.attribute("points", "250", None),
+            test.attribute("points", "300", None),
+        ],
+    );
+    test.client
+        .set_attributes(&metadata_manager, &token_id, &attributes);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #306)")] // DuplicateTraitType
+fn test_mint_with_duplicate_trait_types_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+
+    let mut metadata = test.create_metadata("Badge");
+    metadata.attributes.push_back(NFTAttribute {
+        trait_type: String::from_str(&test.env, "level"),
+        value: String::from_str(&test.env, "silver"),
+        display_type: None,
+        max_value: None,
+    });
+
+    test.client.mint_with_metadata(&minter, &recipient, &metadata);
+}
+
+// ============================================================================
+// Validation Tests
+// ============================================================================
+
+impl TestEnv {
+    fn mint_expecting_error(&self, metadata: &NFTMetadata) {
+        let minter = Address::generate(&self.env);
+        let recipient = Address::generate(&self.env);
+        self.grant_minter_role(&minter);
+        self.client.mint_with_metadata(&minter, &recipient, metadata);
+    }
+
+    fn attribute(&self, trait_type: &str, value: &str, display_type: Option<&str>) -> NFTAttribute {
+        NFTAttribute {
+            trait_type: String::from_str(&self.env, trait_type),
+            value: String::from_str(&self.env, value),
+            display_type: display_type.map(|d| String::from_str(&self.env, d)),
+            max_value: None,
+        }
+    }
+}
+
+#[test]
+fn test_mint_accepts_ipfs_image_and_empty_external_url() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+    test.grant_minter_role(&minter);
+
+    let mut metadata = test.create_metadata("IPFS Badge");
+    metadata.image_uri = String::from_str(&test.env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+    metadata.external_url = String::from_str(&test.env, "");
+    metadata.attributes.push_back(test.attribute("points", "120", Some("number")));
+
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+    assert_eq!(test.client.get_metadata(&token_id).unwrap(), metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #309)")] // EmptyName
+fn test_mint_with_empty_name_fails() {
+    let test = TestEnv::new();
+    test.mint_expecting_error(&test.create_metadata(""));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #314)")] // InvalidUriScheme
+fn test_mint_with_http_image_fails() {
+    let test = TestEnv::new();
+    let mut metadata = test.create_metadata("Badge");
+    metadata.image_uri = String::from_str(&test.env, "http://example.com/image.png");
+    test.mint_expecting_error(&metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #315)")] // TooManyAttributes
+fn test_mint_with_too_many_attributes_fails() {
+    let test = TestEnv::new();
+    let mut metadata = test.create_metadata("Badge");
+    let names = [
+        "a1", "a2", "a3", "a4", "a5", "a6", "a7", "a8", "a9", "a10", "a11", "a12", "a13", "a14",
+        "a15", "a16", "a17", "a18", "a19",
+    ];
+    for name in names {
+        metadata.attributes.push_back(test.attribute(name, "x", None));
+    }
+    test.mint_expecting_error(&metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #318)")] // InvalidDisplayType
+fn test_mint_with_unknown_display_type_fails() {
+    let test = TestEnv::new();
+    let mut metadata = test.create_metadata("Badge");
+    metadata.attributes.push_back(test.attribute("rarity", "rare", Some("color")));
+    test.mint_expecting_error(&metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #319)")] // InvalidNumericValue
+fn test_mint_with_non_numeric_number_fails() {
+    let test = TestEnv::new();
+    let mut metadata = test.create_metadata("Badge");
+    metadata.attributes.push_back(test.attribute("points", "lots", Some("number")));
+    test.mint_expecting_error(&metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #336)")] // ValueExceedsMax
+fn test_mint_with_value_above_max_fails() {
+    let test = TestEnv::new();
+    let mut metadata = test.create_metadata("Badge");
+    let mut attribute = test.attribute("tier", "7", Some("number"));
+    attribute.max_value = Some(String::from_str(&test.env, "5"));
+    metadata.attributes.push_back(attribute);
+    test.mint_expecting_error(&metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")] // InvalidMaxValue
+fn test_mint_with_non_numeric_max_value_fails() {
+    let test = TestEnv::new();
+    let mut metadata = test.create_metadata("Badge");
+    let mut attribute = test.attribute("tier", "3", Some("number"));
+    attribute.max_value = Some(String::from_str(&test.env, "five"));
+    metadata.attributes.push_back(attribute);
+    test.mint_expecting_error(&metadata);
+}
+
+// ============================================================================
+// Freeze and Provenance Tests
+// ============================================================================
+
+#[test]
+fn test_update_metadata_records_history() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge v0");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+    assert_eq!(test.client.get_metadata_revision(&token_id), 0);
+    assert_eq!(test.client.get_metadata_history(&token_id).len(), 0);
+
+    test.client.update_metadata(
+        &metadata_manager,
+        &token_id,
+        &test.create_metadata("Badge v1"),
+    );
+
+    assert_eq!(test.client.get_metadata_revision(&token_id), 1);
+    let history = test.client.get_metadata_history(&token_id);
+    assert_eq!(history.len(), 1);
+
+    let entry = history.get(0).unwrap();
+    assert_eq!(entry.revision, 0);
+    assert_eq!(entry.updated_by, metadata_manager);
+    assert_eq!(
+        entry.metadata_hash,
+        crate::metadata::hash_metadata(&test.env, &metadata)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #308)")] // FieldFrozen
+fn test_update_frozen_token_field_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.freeze_token_metadata(
+        &metadata_manager,
+        &token_id,
+        &FrozenFields {
+            name: true,
+            image_uri: true,
+            ..Default::default()
+        },
+    );
+
+    test.client.update_metadata(
+        &metadata_manager,
+        &token_id,
+        &test.create_metadata("Renamed Badge"),
+    );
+}
+
+#[test]
+fn test_frozen_fields_leave_attributes_mutable() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.freeze_collection_metadata(
+        &test.admin,
+        &FrozenFields {
+            name: true,
+            image_uri: true,
+            ..Default::default()
+        },
+    );
+
+    let frozen = test.client.get_frozen_fields(&token_id);
+    assert!(frozen.name);
+    assert!(frozen.image_uri);
+    assert!(!frozen.attributes);
+
+    test.client.set_attribute(
+        &metadata_manager,
+        &token_id,
+        &String::from_str(&test.env, "level"),
+        &String::from_str(&test.env, "silver"),
+        &None,
+        &None,
+    );
+    assert_eq!(test.client.get_metadata_revision(&token_id), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #308)")] // FieldFrozen
+fn test_set_attribute_on_frozen_attributes_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let metadata_manager = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let metadata = test.create_metadata("Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &recipient, &metadata);
+
+    test.client.freeze_token_metadata(
+        &metadata_manager,
+        &token_id,
+        &FrozenFields {
+            attributes: true,
+            ..Default::default()
+        },
+    );
+
+    test.client.set_attribute(
+        &metadata_manager,
+        &token_id,
+        &String::from_str(&test.env, "level"),
+        &String::from_str(&test.env, "silver"),
+        &None,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")] // Unauthorized
+fn test_freeze_collection_metadata_requires_admin() {
+    let test = TestEnv::new();
+    let metadata_manager = Address::generate(&test.env);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    test.client.freeze_collection_metadata(
+        &metadata_manager,
+        &FrozenFields {
+            name: true,
+            ..Default::default()
+        },
+    );
+}
+
+// ============================================================================
+// Voucher Tests
+// ============================================================================
+
+impl TestEnv {
+    /// Register a deterministic signer key through a new minter.
+    fn register_voucher_signer(&self) -> (Address, SigningKey) {
+        let minter = Address::generate(&self.env);
+        self.grant_minter_role(&minter);
+
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        self.client
+            .register_voucher_signer(&minter, &self.public_key(&signing_key));
+        (minter, signing_key)
+    }
+
+    fn public_key(&self, signing_key: &SigningKey) -> BytesN<32> {
+        BytesN::from_array(&self.env, &signing_key.verifying_key().to_bytes())
+    }
+
+    fn create_voucher(&self, signing_key: &SigningKey, recipient: &Address, metadata: &NFTMetadata, nonce: u64) -> MintVoucher {
+        MintVoucher {
+            recipient: recipient.clone(),
+            metadata_hash: self.client.hash_metadata(metadata),
+            expiry: self.env.ledger().timestamp() + 3600,
+            nonce,
+            signer: self.public_key(signing_key),
+        }
+    }
+
+    fn sign_voucher(&self, signing_key: &SigningKey, voucher: &MintVoucher) -> BytesN<64> {
+        let digest = self.client.get_voucher_digest(voucher);
+        let signature = signing_key.sign(&digest.to_array());
+        BytesN::from_array(&self.env, &signature.to_bytes())
+    }
+}
+
+#[test]
+fn test_claim_with_voucher() {
+    let test = TestEnv::new();
+    let (_, signing_key) = test.register_voucher_signer();
+    let recipient = Address::generate(&test.env);
+
+    let metadata = test.create_metadata("Claimed Badge");
+    let voucher = test.create_voucher(&signing_key, &recipient, &metadata, 1);
+    let signature = test.sign_voucher(&signing_key, &voucher);
+
+    let token_id = test.client.claim_with_voucher(&voucher, &metadata, &signature);
+
+    assert_eq!(test.client.owner_of(&token_id), recipient);
+    assert_eq!(test.client.get_metadata(&token_id).unwrap(), metadata);
+    assert!(test.client.is_voucher_nonce_used(&voucher.signer, &1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #323)")] // VoucherNonceUsed
+fn test_claim_with_voucher_twice_fails() {
+    let test = TestEnv::new();
+    let (_, signing_key) = test.register_voucher_signer();
+    let recipient = Address::generate(&test.env);
+
+    let metadata = test.create_metadata("Claimed Badge");
+    let voucher = test.create_voucher(&signing_key, &recipient, &metadata, 1);
+    let signature = test.sign_voucher(&signing_key, &voucher);
+
+    test.client.claim_with_voucher(&voucher, &metadata, &signature);
+    test.client.claim_with_voucher(&voucher, &metadata, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #322)")] // VoucherExpired
+fn test_claim_expired_voucher_fails() {
+    let test = TestEnv::new();
+    let (_, signing_key) = test.register_voucher_signer();
+    let recipient = Address::generate(&test.env);
+
+    let metadata = test.create_metadata("Claimed Badge");
+    let voucher = test.create_voucher(&signing_key, &recipient, &metadata, 1);
+    let signature = test.sign_voucher(&signing_key, &voucher);
+
+    test.env.ledger().with_mut(|li| li.timestamp = voucher.expiry + 1);
+    test.client.claim_with_voucher(&voucher, &metadata, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #324)")] // VoucherMetadataMismatch
+fn test_claim_voucher_with_other_metadata_fails() {
+    let test = TestEnv::new();
+    let (_, signing_key) = test.register_voucher_signer();
+    let recipient = Address::generate(&test.env);
+
+    let metadata = test.create_metadata("Claimed Badge");
+    let voucher = test.create_voucher(&signing_key, &recipient, &metadata, 1);
+    let signature = test.sign_voucher(&signing_key, &voucher);
+
+    let other_metadata = test.create_metadata("Diamond Badge");
+    test.client.claim_with_voucher(&voucher, &other_metadata, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #321)")] // UnknownVoucherSigner
+fn test_claim_voucher_after_minter_role_revoked_fails() {
+    let test = TestEnv::new();
+    let (minter, signing_key) = test.register_voucher_signer();
+    let recipient = Address::generate(&test.env);
+
+    let metadata = test.create_metadata("Claimed Badge");
+    let voucher = test.create_voucher(&signing_key, &recipient, &metadata, 1);
+    let signature = test.sign_voucher(&signing_key, &voucher);
+
+    test.client
+        .revoke_role(&minter, &symbol_short!("minter"), &test.admin);
+    test.client.claim_with_voucher(&voucher, &metadata, &signature);
+}
+
+#[test]
+#[should_panic]
+fn test_claim_voucher_with_bad_signature_fails() {
+    let test = TestEnv::new();
+    let (_, signing_key) = test.register_voucher_signer();
+    let recipient = Address::generate(&test.env);
+
+    let metadata = test.create_metadata("Claimed Badge");
+    let voucher = test.create_voucher(&signing_key, &recipient, &metadata, 1);
+
+    // Signature over a voucher for a different recipient
+    let other_voucher = test.create_voucher(&signing_key, &Address::generate(&test.env), &metadata, 1);
+    let signature = test.sign_voucher(&signing_key, &other_voucher);
+
+    test.client.claim_with_voucher(&voucher, &metadata, &signature);
+}
+
+// ============================================================================
+// Airdrop Tests
+// ============================================================================
+
+/// Hash two Merkle nodes in sorted order, mirroring the contract.
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from(first.clone());
+    data.append(&Bytes::from(second.clone()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+impl TestEnv {
+    /// Publish a two-leaf allowlist for template 1 and open the claim window.
+    fn setup_airdrop(&self, alice: &Address, bob: &Address) -> (BytesN<32>, BytesN<32>) {
+        self.client
+            .create_metadata_template(&self.admin, &1, &self.create_metadata("Earth Week 2026"));
+
+        let alice_leaf = self.client.get_airdrop_leaf(alice, &1);
+        let bob_leaf = self.client.get_airdrop_leaf(bob, &1);
+        let root = hash_pair(&self.env, &alice_leaf, &bob_leaf);
+
+        self.client.set_airdrop_root(&self.admin, &root);
+        self.client.open_airdrop_claims(&self.admin, &0, &1000);
+        (alice_leaf, bob_leaf)
+    }
+}
+
+#[test]
+fn test_airdrop_claim_mints_from_template() {
+    let test = TestEnv::new();
+    let alice = Address::generate(&test.env);
+    let bob = Address::generate(&test.env);
+    let (_, bob_leaf) = test.setup_airdrop(&alice, &bob);
+
+    let proof = Vec::from_array(&test.env, [bob_leaf]);
+    let token_id = test.client.claim(&alice, &1, &proof);
+
+    assert_eq!(test.client.owner_of(&token_id), alice);
+    assert_eq!(test.client.get_token_template(&token_id), Some(1));
+    assert_eq!(
+        test.client.get_metadata(&token_id),
+        test.client.get_metadata_template(&1)
+    );
+    assert!(test.client.is_airdrop_claimed(&alice, &1));
+    assert!(!test.client.is_airdrop_claimed(&bob, &1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #330)")] // AirdropAlreadyClaimed
+fn test_airdrop_claim_twice_fails() {
+    let test = TestEnv::new();
+    let alice = Address::generate(&test.env);
+    let bob = Address::generate(&test.env);
+    let (_, bob_leaf) = test.setup_airdrop(&alice, &bob);
+
+    let proof = Vec::from_array(&test.env, [bob_leaf]);
+    test.client.claim(&alice, &1, &proof);
+    test.client.claim(&alice, &1, &proof);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #329)")] // InvalidMerkleProof
+fn test_airdrop_claim_not_on_allowlist_fails() {
+    let test = TestEnv::new();
+    let alice = Address::generate(&test.env);
+    let bob = Address::generate(&test.env);
+    let (alice_leaf, _) = test.setup_airdrop(&alice, &bob);
+
+    let mallory = Address::generate(&test.env);
+    let proof = Vec::from_array(&test.env, [alice_leaf]);
+    test.client.claim(&mallory, &1, &proof);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #328)")] // AirdropClosed
+fn test_airdrop_claim_after_close_fails() {
+    let test = TestEnv::new();
+    let alice = Address::generate(&test.env);
+    let bob = Address::generate(&test.env);
+    let (_, bob_leaf) = test.setup_airdrop(&alice, &bob);
+
+    test.client.close_airdrop_claims(&test.admin);
+
+    let proof = Vec::from_array(&test.env, [bob_leaf]);
+    test.client.claim(&alice, &1, &proof);
+}
+
+#[test]
+fn test_template_token_gets_own_copy_on_update() {
+    let test = TestEnv::new();
+    let metadata_manager = Address::generate(&test.env);
+    let alice = Address::generate(&test.env);
+    let bob = Address::generate(&test.env);
+    let (alice_leaf, bob_leaf) = test.setup_airdrop(&alice, &bob);
+    test.grant_metadata_manager_role(&metadata_manager);
+
+    let alice_token = test.client.claim(&alice, &1, &Vec::from_array(&test.env, [bob_leaf]));
+    let bob_token = test.client.claim(&bob, &1, &Vec::from_array(&test.env, [alice_leaf]));
+
+    test.client.set_attribute(
+        &metadata_manager,
+        &alice_token,
+        &String::from_str(&test.env, "level"),
+        &String::from_str(&test.env, "gold"),
+        &None,
+        &None,
+    );
+
+    // Only Alice's token changes; the template and Bob's token are untouched
+    let template = test.client.get_metadata_template(&1).unwrap();
+    assert_ne!(test.client.get_metadata(&alice_token).unwrap(), template);
+    assert_eq!(test.client.get_metadata(&bob_token).unwrap(), template);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #326)")] // TemplateAlreadyExists
+fn test_create_template_twice_fails() {
+    let test = TestEnv::new();
+    let metadata = test.create_metadata("Template");
+    test.client
+        .create_metadata_template(&test.admin, &1, &metadata);
+    test.client
+        .create_metadata_template(&test.admin, &1, &metadata);
+}
+
+// ============================================================================
+// Royalty Tests
+// ============================================================================
+
+#[test]
+fn test_default_royalty_applies_to_all_tokens() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    let campaign = Address::generate(&test.env);
+    test.grant_minter_role(&minter);
+
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &user, &test.create_metadata("Royalty NFT"));
+
+    assert_eq!(test.client.royalty_info(&token_id, &10_000), None);
+
+    test.client.set_default_royalty(&test.admin, &campaign, &500);
+
+    assert_eq!(
+        test.client.royalty_info(&token_id, &10_000),
+        Some((campaign, 500))
+    );
+}
+
+#[test]
+fn test_token_royalty_overrides_default() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    let campaign = Address::generate(&test.env);
+    let artist = Address::generate(&test.env);
+    test.grant_minter_role(&minter);
+
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &user, &test.create_metadata("Royalty NFT"));
+
+    test.client.set_default_royalty(&test.admin, &campaign, &500);
+    test.client
+        .set_token_royalty(&test.admin, &token_id, &artist, &1_000);
+
+    assert_eq!(
+        test.client.royalty_info(&token_id, &10_000),
+        Some((artist, 1_000))
+    );
+
+    // Removing the override falls back to the default
+    test.client.remove_token_royalty(&test.admin, &token_id);
+    assert_eq!(
+        test.client.royalty_info(&token_id, &10_000),
+        Some((campaign, 500))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #200)")] // NonExistentToken
+fn test_remove_token_royalty_of_nonexistent_token_fails() {
+    let test = TestEnv::new();
+
+    // Token 999 was never minted
+    test.client.remove_token_royalty(&test.admin, &999);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #332)")] // InvalidRoyalty
+fn test_royalty_above_100_percent_fails() {
+    let test = TestEnv::new();
+    let campaign = Address::generate(&test.env);
+
+    test.client.set_default_royalty(&test.admin, &campaign, &10_001);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #333)")] // InvalidSalePrice
+fn test_royalty_info_negative_price_fails() {
+    let test = TestEnv::new();
+
+    test.client.royalty_info(&0, &-1);
+}
+
+#[test]
+fn test_set_contract_metadata() {
+    let test = TestEnv::new();
+    assert_eq!(test.client.contract_metadata(), None);
+
+    let contract_metadata = ContractMetadata {
+        description: String::from_str(&test.env, "KindFi supporter collection"),
+        image: String::from_str(&test.env, "ipfs://QmCollectionImage"),
+        external_link: String::from_str(&test.env, "https://kindfi.org"),
+    };
+    test.client
+        .set_contract_metadata(&test.admin, &contract_metadata);
+
+    assert_eq!(test.client.contract_metadata(), Some(contract_metadata));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #314)")] // InvalidUriScheme
+fn test_set_contract_metadata_with_http_link_fails() {
+    let test = TestEnv::new();
+
+    test.client.set_contract_metadata(
+        &test.admin,
+        &ContractMetadata {
+            description: String::from_str(&test.env, "KindFi supporter collection"),
+            image: String::from_str(&test.env, ""),
+            external_link: String::from_str(&test.env, "http://kindfi.org"),
+        },
+    );
+}
+
 // ============================================================================
 // Burn Tests
 // ============================================================================
@@ -324,6 +1174,101 @@ fn test_burn_without_role_fails() {
     test.client.burn(&owner, &token_id);
 }
 
+#[test]
+fn test_burn_records_tombstone_and_supply() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let burner = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_burner_role(&burner);
+
+    let metadata = test.create_metadata("Burnable NFT");
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &burner, &metadata);
+    test.client
+        .mint_with_metadata(&minter, &burner, &test.create_metadata("Kept NFT"));
+
+    test.client.burn(&burner, &token_id);
+
+    assert_eq!(test.client.total_supply(), 2);
+    assert_eq!(test.client.burned_count(), 1);
+    assert_eq!(test.client.circulating_supply(), 1);
+
+    let tombstone = test.client.get_tombstone(&token_id).unwrap();
+    assert_eq!(tombstone.last_owner, burner);
+    assert_eq!(tombstone.burned_at_ledger, test.env.ledger().sequence());
+    assert_eq!(
+        tombstone.metadata_hash,
+        test.client.hash_metadata(&metadata)
+    );
+}
+
+#[test]
+fn test_reissue_burned_token() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let burner = Address::generate(&test.env);
+    let recovered = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_burner_role(&burner);
+
+    let metadata = test.create_metadata("Lost Badge");
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &burner, &metadata);
+    test.client.burn(&burner, &token_id);
+
+    test.client
+        .reissue_burned(&test.admin, &token_id, &recovered, &metadata);
+
+    assert_eq!(test.client.owner_of(&token_id), recovered);
+    assert_eq!(test.client.get_metadata(&token_id), Some(metadata));
+    assert_eq!(test.client.get_tombstone(&token_id), None);
+    assert_eq!(test.client.burned_count(), 0);
+    assert_eq!(test.client.circulating_supply(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #335)")] // TombstoneMetadataMismatch
+fn test_reissue_with_different_metadata_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let burner = Address::generate(&test.env);
+
+    test.grant_minter_role(&minter);
+    test.grant_burner_role(&burner);
+
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &burner, &test.create_metadata("Lost Badge"));
+    test.client.burn(&burner, &token_id);
+
+    test.client.reissue_burned(
+        &test.admin,
+        &token_id,
+        &burner,
+        &test.create_metadata("Other Badge"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #334)")] // TokenNotBurned
+fn test_reissue_live_token_fails() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let owner = Address::generate(&test.env);
+    test.grant_minter_role(&minter);
+
+    let metadata = test.create_metadata("Live Badge");
+    let token_id = test.client.mint_with_metadata(&minter, &owner, &metadata);
+
+    test.client
+        .reissue_burned(&test.admin, &token_id, &owner, &metadata);
+}
+
 // ============================================================================
 // Transfer Tests
 // ============================================================================
@@ -379,6 +1324,106 @@ fn test_transfer_from_with_approval() {
     assert_eq!(test.client.owner_of(&token_id), recipient);
 }
 
+// ============================================================================
+// Transfer Hook Tests
+// ============================================================================
+
+/// Hook contract that records the last notification it received.
+#[contract]
+struct MockTransferHook;
+
+#[contractimpl]
+impl MockTransferHook {
+    pub fn on_nft_transfer(e: Env, token_id: u32, from: Address, to: Option<Address>) {
+        e.storage()
+            .instance()
+            .set(&symbol_short!("last"), &(token_id, from, to));
+    }
+
+    pub fn last_transfer(e: Env) -> Option<(u32, Address, Option<Address>)> {
+        e.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+impl TestEnv {
+    fn register_transfer_hook(&self) -> MockTransferHookClient<'static> {
+        let hook_id = self.env.register(MockTransferHook, ());
+        self.client.set_transfer_hook(&self.admin, &Some(hook_id.clone()));
+        MockTransferHookClient::new(&self.env, &hook_id)
+    }
+}
+
+#[test]
+fn test_transfer_notifies_hook() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let owner = Address::generate(&test.env);
+    let recipient = Address::generate(&test.env);
+    test.grant_minter_role(&minter);
+    let hook = test.register_transfer_hook();
+
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &owner, &test.create_metadata("Hooked NFT"));
+    test.client.transfer(&owner, &recipient, &token_id);
+
+    assert_eq!(
+        hook.last_transfer(),
+        Some((token_id, owner, Some(recipient)))
+    );
+}
+
+#[test]
+fn test_burn_notifies_hook() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let burner = Address::generate(&test.env);
+    test.grant_minter_role(&minter);
+    test.grant_burner_role(&burner);
+    let hook = test.register_transfer_hook();
+
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &burner, &test.create_metadata("Hooked NFT"));
+    test.client.burn(&burner, &token_id);
+
+    assert_eq!(hook.last_transfer(), Some((token_id, burner, None)));
+}
+
+#[test]
+fn test_reissue_notifies_hook() {
+    let test = TestEnv::new();
+    let minter = Address::generate(&test.env);
+    let burner = Address::generate(&test.env);
+    let recovered = Address::generate(&test.env);
+    test.grant_minter_role(&minter);
+    test.grant_burner_role(&burner);
+    let hook = test.register_transfer_hook();
+
+    let metadata = test.create_metadata("Hooked NFT");
+    let token_id = test
+        .client
+        .mint_with_metadata(&minter, &burner, &metadata);
+    test.client.burn(&burner, &token_id);
+    test.client
+        .reissue_burned(&test.admin, &token_id, &recovered, &metadata);
+
+    assert_eq!(
+        hook.last_transfer(),
+        Some((token_id, burner, Some(recovered)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")] // Unauthorized
+fn test_set_transfer_hook_requires_admin() {
+    let test = TestEnv::new();
+    let other = Address::generate(&test.env);
+    let hook = Address::generate(&test.env);
+
+    test.client.set_transfer_hook(&other, &Some(hook));
+}
+
 // ============================================================================
 // Approval Tests
 // ============================================================================
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 /// NFT attribute following SEP-0050 JSON schema.
 /// Represents a single trait/property of the NFT.
@@ -43,6 +43,102 @@ pub struct NFTMetadata {
     pub attributes: Vec<NFTAttribute>,
 }
 
+/// Metadata fields that can be frozen against further updates.
+/// A field set to `true` can no longer be changed; freezing is permanent.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FrozenFields {
+    pub name: bool,
+    pub description: bool,
+    pub image_uri: bool,
+    pub external_url: bool,
+    pub attributes: bool,
+}
+
+/// Provenance record of a replaced metadata version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataRevision {
+    /// Revision number of the replaced version (0 = metadata as minted)
+    pub revision: u32,
+    /// SHA-256 hash of the XDR-encoded metadata of that version
+    pub metadata_hash: BytesN<32>,
+    /// Address that replaced the version
+    pub updated_by: Address,
+    /// Ledger sequence at which the version was replaced
+    pub replaced_at_ledger: u32,
+}
+
+/// Mint authorization signed off-chain by a registered voucher signer.
+///
+/// The signer signs `get_voucher_digest(voucher)`, which binds the voucher to
+/// this contract's address. The claimed token gets exactly the metadata whose
+/// hash is `metadata_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintVoucher {
+    /// Address that receives the NFT
+    pub recipient: Address,
+    /// SHA-256 hash of the XDR-encoded NFTMetadata to mint
+    pub metadata_hash: BytesN<32>,
+    /// Ledger timestamp after which the voucher can no longer be claimed
+    pub expiry: u64,
+    /// Nonce scoped to the signer; each nonce can be claimed once
+    pub nonce: u64,
+    /// Ed25519 public key that signed the voucher
+    pub signer: BytesN<32>,
+}
+
+/// Merkle allowlist airdrop configuration.
+///
+/// Leaves are `sha256(xdr((address, template_id)))` and pairs are hashed in
+/// sorted order, so proofs do not need position flags.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropConfig {
+    /// Merkle root of the allowlist
+    pub merkle_root: BytesN<32>,
+    /// Ledger timestamp at which claims open
+    pub claims_open_at: u64,
+    /// Ledger timestamp at which claims close (exclusive)
+    pub claims_close_at: u64,
+}
+
+/// Royalty receiver and rate for secondary sales (EIP-2981 style).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoyaltyInfo {
+    /// Address that receives the royalty (e.g. the campaign)
+    pub receiver: Address,
+    /// Royalty rate in basis points (10000 = 100%)
+    pub basis_points: u32,
+}
+
+/// Collection-level metadata, complementing the name, symbol and base URI
+/// stored by `Base::set_metadata`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMetadata {
+    /// Description of the collection
+    pub description: String,
+    /// URI of the collection image
+    pub image: String,
+    /// External link for the collection (e.g. the campaign page)
+    pub external_link: String,
+}
+
+/// Record kept for a burned token so it can be audited and re-issued.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tombstone {
+    /// Owner of the token when it was burned
+    pub last_owner: Address,
+    /// Ledger sequence at which the token was burned
+    pub burned_at_ledger: u32,
+    /// SHA-256 hash of the XDR-encoded metadata at burn time
+    pub metadata_hash: BytesN<32>,
+}
+
 /// Storage keys for the KindFi NFT contract.
 #[contracttype]
 #[derive(Clone)]
@@ -51,4 +147,36 @@ pub enum StorageKey {
     TokenCounter,
     /// Custom metadata storage per token ID
     TokenMetadata(u32),
+    /// Fields frozen for every token in the collection
+    CollectionFrozenFields,
+    /// Fields frozen for a single token: TokenFrozenFields(u32) -> FrozenFields
+    TokenFrozenFields(u32),
+    /// Current metadata revision number: TokenRevision(u32) -> u32
+    TokenRevision(u32),
+    /// Bounded history of replaced versions: TokenHistory(u32) -> Vec<MetadataRevision>
+    TokenHistory(u32),
+    /// Registered voucher signer key -> minter that registered it
+    VoucherSigner(BytesN<32>),
+    /// Consumed voucher nonce: VoucherNonce(signer, nonce) -> bool
+    VoucherNonce(BytesN<32>, u64),
+    /// Shared metadata template: MetadataTemplate(u32) -> NFTMetadata
+    MetadataTemplate(u32),
+    /// Template a token was minted from: TokenTemplate(u32) -> u32
+    TokenTemplate(u32),
+    /// Current airdrop configuration
+    AirdropConfig,
+    /// Claimed airdrop leaf: AirdropClaimed(leaf) -> bool
+    AirdropClaimed(BytesN<32>),
+    /// Collection-wide default royalty
+    DefaultRoyalty,
+    /// Per-token royalty override: TokenRoyalty(u32) -> RoyaltyInfo
+    TokenRoyalty(u32),
+    /// Collection-level metadata
+    ContractMetadata,
+    /// Number of tokens currently burned
+    BurnedCount,
+    /// Burn record per token ID: Tombstone(u32) -> Tombstone
+    Tombstone(u32),
+    /// Contract notified when a token changes owner or is burned
+    TransferHook,
 }
\ No newline at end of file
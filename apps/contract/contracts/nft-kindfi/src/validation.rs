@@ -0,0 +1,185 @@
+use soroban_sdk::{panic_with_error, Env, String, Vec};
+
+use crate::errors::Error;
+use crate::types::{ContractMetadata, NFTAttribute, NFTMetadata};
+
+/// Maximum length of a token name, in bytes.
+pub const MAX_NAME_LENGTH: u32 = 64;
+
+/// Maximum length of a token description, in bytes.
+pub const MAX_DESCRIPTION_LENGTH: u32 = 512;
+
+/// Maximum length of an image URI or external URL, in bytes.
+pub const MAX_URI_LENGTH: u32 = 256;
+
+/// Maximum number of attributes per token.
+pub const MAX_ATTRIBUTES: u32 = 20;
+
+/// Maximum length of an attribute trait_type, in bytes.
+pub const MAX_TRAIT_TYPE_LENGTH: u32 = 32;
+
+/// Maximum length of an attribute value or max_value, in bytes.
+pub const MAX_ATTRIBUTE_VALUE_LENGTH: u32 = 64;
+
+/// URI schemes accepted for image_uri and external_url.
+const ALLOWED_URI_SCHEMES: [&[u8]; 2] = [b"ipfs://", b"https://"];
+
+/// Display types accepted by SEP-0050 compatible metadata.
+const DISPLAY_TYPES: [&str; 5] = ["string", "number", "boost_number", "boost_percentage", "date"];
+
+/// Display types whose value must be numeric.
+const NUMERIC_DISPLAY_TYPES: [&str; 4] = ["number", "boost_number", "boost_percentage", "date"];
+
+// ============================================================================
+// Metadata Validation
+// ============================================================================
+
+/// Validate a full metadata value.
+/// Panics with a specific error code for the first rule that fails.
+pub fn require_valid_metadata(e: &Env, metadata: &NFTMetadata) {
+    if metadata.name.is_empty() {
+        panic_with_error!(e, Error::EmptyName);
+    }
+    if metadata.name.len() > MAX_NAME_LENGTH {
+        panic_with_error!(e, Error::NameTooLong);
+    }
+    if metadata.description.len() > MAX_DESCRIPTION_LENGTH {
+        panic_with_error!(e, Error::DescriptionTooLong);
+    }
+
+    if metadata.image_uri.is_empty() {
+        panic_with_error!(e, Error::EmptyImageUri);
+    }
+    require_valid_uri(e, &metadata.image_uri);
+
+    // The external URL is optional
+    if !metadata.external_url.is_empty() {
+        require_valid_uri(e, &metadata.external_url);
+    }
+
+    require_valid_attributes(e, &metadata.attributes);
+}
+
+/// Validate collection-level metadata. The image and external link are
+/// optional but must use an allowed scheme when set.
+pub fn require_valid_contract_metadata(e: &Env, metadata: &ContractMetadata) {
+    if metadata.description.len() > MAX_DESCRIPTION_LENGTH {
+        panic_with_error!(e, Error::DescriptionTooLong);
+    }
+    if !metadata.image.is_empty() {
+        require_valid_uri(e, &metadata.image);
+    }
+    if !metadata.external_link.is_empty() {
+        require_valid_uri(e, &metadata.external_link);
+    }
+}
+
+/// Validate a list of attributes: count, unique trait types and each attribute.
+pub fn require_valid_attributes(e: &Env, attributes: &Vec<NFTAttribute>) {
+    if attributes.len() > MAX_ATTRIBUTES {
+        panic_with_error!(e, Error::TooManyAttributes);
+    }
+
+    for i in 0..attributes.len() {
+        let attribute = attributes.get_unchecked(i);
+        require_valid_attribute(e, &attribute);
+
+        for j in (i + 1)..attributes.len() {
+            if attributes.get_unchecked(j).trait_type == attribute.trait_type {
+                panic_with_error!(e, Error::DuplicateTraitType);
+            }
+        }
+    }
+}
+
+/// Validate a single attribute.
+///
+/// `max_value` must be numeric when present. When the display type is numeric,
+/// `value` must be numeric too and must not exceed `max_value`.
+pub fn require_valid_attribute(e: &Env, attribute: &NFTAttribute) {
+    if attribute.trait_type.is_empty() || attribute.trait_type.len() > MAX_TRAIT_TYPE_LENGTH {
+        panic_with_error!(e, Error::InvalidTraitType);
+    }
+    if attribute.value.is_empty() || attribute.value.len() > MAX_ATTRIBUTE_VALUE_LENGTH {
+        panic_with_error!(e, Error::InvalidAttributeValue);
+    }
+
+    let max_value = match &attribute.max_value {
+        Some(max_value) => match parse_unsigned(max_value) {
+            Some(parsed) => Some(parsed),
+            None => panic_with_error!(e, Error::InvalidMaxValue),
+        },
+        None => None,
+    };
+
+    let Some(display_type) = &attribute.display_type else {
+        return;
+    };
+    if !is_one_of(e, display_type, &DISPLAY_TYPES) {
+        panic_with_error!(e, Error::InvalidDisplayType);
+    }
+
+    if is_one_of(e, display_type, &NUMERIC_DISPLAY_TYPES) {
+        let Some(value) = parse_unsigned(&attribute.value) else {
+            panic_with_error!(e, Error::InvalidNumericValue);
+        };
+        if let Some(max_value) = max_value {
+            if value > max_value {
+                panic_with_error!(e, Error::ValueExceedsMax);
+            }
+        }
+    }
+}
+
+/// Validate a URI: length limit and an allowed scheme (ipfs:// or https://).
+pub fn require_valid_uri(e: &Env, uri: &String) {
+    if uri.len() > MAX_URI_LENGTH {
+        panic_with_error!(e, Error::UriTooLong);
+    }
+
+    let len = uri.len() as usize;
+    let mut buffer = [0u8; MAX_URI_LENGTH as usize];
+    uri.copy_into_slice(&mut buffer[..len]);
+    let bytes = &buffer[..len];
+
+    let allowed = ALLOWED_URI_SCHEMES
+        .iter()
+        .any(|scheme| bytes.len() > scheme.len() && bytes.starts_with(scheme));
+    if !allowed {
+        panic_with_error!(e, Error::InvalidUriScheme);
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Check whether a string equals one of the given values.
+fn is_one_of(e: &Env, value: &String, candidates: &[&str]) -> bool {
+    candidates
+        .iter()
+        .any(|candidate| *value == String::from_str(e, candidate))
+}
+
+/// Parse a non-negative integer written in ASCII digits.
+/// Returns None for empty, non-digit or overflowing input.
+fn parse_unsigned(value: &String) -> Option<u64> {
+    let len = value.len() as usize;
+    if len == 0 || len > MAX_ATTRIBUTE_VALUE_LENGTH as usize {
+        return None;
+    }
+
+    let mut buffer = [0u8; MAX_ATTRIBUTE_VALUE_LENGTH as usize];
+    value.copy_into_slice(&mut buffer[..len]);
+
+    let mut parsed: u64 = 0;
+    for byte in &buffer[..len] {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        parsed = parsed
+            .checked_mul(10)?
+            .checked_add(u64::from(byte - b'0'))?;
+    }
+    Some(parsed)
+}
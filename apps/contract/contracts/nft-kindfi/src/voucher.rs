@@ -0,0 +1,117 @@
+use soroban_sdk::{panic_with_error, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol};
+use stellar_access::access_control::has_role;
+
+use crate::errors::Error;
+use crate::events::VoucherClaimedEventData;
+use crate::metadata;
+use crate::mint;
+use crate::types::{MintVoucher, NFTMetadata, StorageKey};
+use crate::MINTER_ROLE;
+
+/// TTL constants for voucher storage (30 days in ledgers)
+const DAY_IN_LEDGERS: u32 = 17280;
+const VOUCHER_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const VOUCHER_TTL_THRESHOLD: u32 = VOUCHER_TTL_AMOUNT - DAY_IN_LEDGERS;
+
+// ============================================================================
+// Signer Registry
+// ============================================================================
+
+/// Get the minter that registered a signer key, if the key is registered.
+pub fn get_signer(e: &Env, public_key: &BytesN<32>) -> Option<Address> {
+    let key = StorageKey::VoucherSigner(public_key.clone());
+    let registered_by: Option<Address> = e.storage().persistent().get(&key);
+    if registered_by.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, VOUCHER_TTL_THRESHOLD, VOUCHER_TTL_AMOUNT);
+    }
+    registered_by
+}
+
+/// Register a signer key on behalf of a minter.
+pub fn set_signer(e: &Env, public_key: &BytesN<32>, registered_by: &Address) {
+    let key = StorageKey::VoucherSigner(public_key.clone());
+    e.storage().persistent().set(&key, registered_by);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, VOUCHER_TTL_THRESHOLD, VOUCHER_TTL_AMOUNT);
+}
+
+/// Remove a signer key.
+pub fn remove_signer(e: &Env, public_key: &BytesN<32>) {
+    let key = StorageKey::VoucherSigner(public_key.clone());
+    e.storage().persistent().remove(&key);
+}
+
+// ============================================================================
+// Nonces
+// ============================================================================
+
+/// Check whether a signer's nonce has already been consumed.
+pub fn is_nonce_used(e: &Env, signer: &BytesN<32>, nonce: u64) -> bool {
+    let key = StorageKey::VoucherNonce(signer.clone(), nonce);
+    e.storage().persistent().has(&key)
+}
+
+/// Mark a signer's nonce as consumed.
+fn consume_nonce(e: &Env, signer: &BytesN<32>, nonce: u64) {
+    let key = StorageKey::VoucherNonce(signer.clone(), nonce);
+    e.storage().persistent().set(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, VOUCHER_TTL_THRESHOLD, VOUCHER_TTL_AMOUNT);
+}
+
+// ============================================================================
+// Claiming
+// ============================================================================
+
+/// Compute the message a signer must sign for a voucher.
+/// The digest covers this contract's address so vouchers cannot be replayed
+/// against another deployment.
+pub fn voucher_digest(e: &Env, voucher: &MintVoucher) -> BytesN<32> {
+    let payload = (e.current_contract_address(), voucher.clone()).to_xdr(e);
+    e.crypto().sha256(&payload).to_bytes()
+}
+
+/// Verify a voucher and its signature, consume its nonce and mint the NFT.
+/// Returns the minted token ID.
+pub fn claim(e: &Env, voucher: &MintVoucher, nft_metadata: &NFTMetadata, signature: &BytesN<64>) -> u32 {
+    // The key must be registered by an address that still holds the minter role
+    let Some(registered_by) = get_signer(e, &voucher.signer) else {
+        panic_with_error!(e, Error::UnknownVoucherSigner);
+    };
+    if has_role(e, &registered_by, &Symbol::new(e, MINTER_ROLE)).is_none() {
+        panic_with_error!(e, Error::UnknownVoucherSigner);
+    }
+
+    if e.ledger().timestamp() > voucher.expiry {
+        panic_with_error!(e, Error::VoucherExpired);
+    }
+    if is_nonce_used(e, &voucher.signer, voucher.nonce) {
+        panic_with_error!(e, Error::VoucherNonceUsed);
+    }
+    if metadata::hash_metadata(e, nft_metadata) != voucher.metadata_hash {
+        panic_with_error!(e, Error::VoucherMetadataMismatch);
+    }
+
+    // Panics if the signature is invalid
+    let message: Bytes = voucher_digest(e, voucher).into();
+    e.crypto()
+        .ed25519_verify(&voucher.signer, &message, signature);
+
+    consume_nonce(e, &voucher.signer, voucher.nonce);
+
+    let token_id = mint::mint_with_metadata(e, &voucher.recipient, nft_metadata);
+
+    VoucherClaimedEventData {
+        token_id,
+        recipient: voucher.recipient.clone(),
+        signer: voucher.signer.clone(),
+        nonce: voucher.nonce,
+    }
+    .publish(e);
+
+    token_id
+}
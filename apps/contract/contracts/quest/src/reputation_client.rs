@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, ConversionError, Env, IntoVal, InvokeError, Symbol};
+use soroban_sdk::{Address, BytesN, ConversionError, Env, IntoVal, InvokeError, Symbol};
 
 /// Client for interacting with the Reputation contract.
 pub struct ReputationClient;
@@ -29,7 +29,8 @@ impl ReputationClient {
             e.try_invoke_contract(
                 reputation_contract,
                 &Symbol::new(e, "record_event"),
-                (caller, user, event_type).into_val(e),
+                // No external reference: this call is not retried
+                (caller, user, event_type, Option::<BytesN<32>>::None).into_val(e),
             );
 
         match result {
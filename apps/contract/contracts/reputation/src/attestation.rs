@@ -0,0 +1,69 @@
+//! Signed reputation attestations.
+//!
+//! The attester service signs `digest` of an attestation with the ed25519
+//! key configured on the contract. The digest is the SHA-256 hash of the XDR
+//! encoding of `(contract address, attestation)`, so partners can recompute
+//! it off-chain, and attestations cannot be replayed against another
+//! deployment.
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env};
+
+use crate::types::Attestation;
+
+/// Compute the message the attester signs for an attestation issued by
+/// `contract`.
+pub fn digest(e: &Env, contract: &Address, attestation: &Attestation) -> BytesN<32> {
+    let payload = (contract.clone(), attestation.clone()).to_xdr(e);
+    e.crypto().sha256(&payload).to_bytes()
+}
+
+/// Check whether an attestation has expired at `now`.
+pub fn is_expired(attestation: &Attestation, now: u64) -> bool {
+    now > attestation.expires_at
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn attestation(env: &Env) -> Attestation {
+        Attestation {
+            user: Address::generate(env),
+            level: 3,
+            points: 1200,
+            ledger: 42,
+            expires_at: 1000,
+            attester: BytesN::from_array(env, &[1u8; 32]),
+        }
+    }
+
+    #[test]
+    fn test_digest_covers_contract_and_fields() {
+        let env = Env::default();
+        let contract = Address::generate(&env);
+        let original = attestation(&env);
+        let mut raised = original.clone();
+        raised.level = 4;
+
+        assert_eq!(
+            digest(&env, &contract, &original),
+            digest(&env, &contract, &original.clone())
+        );
+        assert_ne!(
+            digest(&env, &contract, &original),
+            digest(&env, &contract, &raised)
+        );
+        assert_ne!(
+            digest(&env, &contract, &original),
+            digest(&env, &Address::generate(&env), &original)
+        );
+    }
+
+    #[test]
+    fn test_expires_after_expiry_timestamp() {
+        let env = Env::default();
+        let attestation = attestation(&env);
+        assert!(!is_expired(&attestation, 1000));
+        assert!(is_expired(&attestation, 1001));
+    }
+}
@@ -0,0 +1,92 @@
+//! Bonus campaign multipliers.
+//!
+//! A campaign multiplies the points of some event types by `multiplier_bps`
+//! (20000 = 2x) during `[start_time, end_time)`. When several campaigns
+//! cover the same event, only the highest multiplier applies.
+use soroban_sdk::Map;
+
+use crate::decay::BPS_DENOMINATOR;
+use crate::types::{BonusCampaign, MAX_MULTIPLIER_BPS};
+
+/// Check that a campaign's multiplier and time window are usable.
+pub fn is_valid_campaign(campaign: &BonusCampaign) -> bool {
+    !campaign.event_types.is_empty()
+        && campaign.multiplier_bps > BPS_DENOMINATOR
+        && campaign.multiplier_bps <= MAX_MULTIPLIER_BPS
+        && campaign.start_time < campaign.end_time
+}
+
+/// Check whether a campaign is running at `now`.
+pub fn is_running(campaign: &BonusCampaign, now: u64) -> bool {
+    campaign.start_time <= now && now < campaign.end_time
+}
+
+/// Get the multiplier for an event type at `now` (10000 if no campaign
+/// applies).
+pub fn multiplier_for(campaigns: &Map<u32, BonusCampaign>, event_type: u32, now: u64) -> u32 {
+    campaigns
+        .values()
+        .iter()
+        .filter(|campaign| is_running(campaign, now) && campaign.event_types.contains(event_type))
+        .map(|campaign| campaign.multiplier_bps)
+        .max()
+        .unwrap_or(BPS_DENOMINATOR)
+}
+
+/// Apply a multiplier to points, rounding down. Returns None on overflow.
+pub fn boosted_points(points: u32, multiplier_bps: u32) -> Option<u32> {
+    let boosted = u64::from(points) * u64::from(multiplier_bps) / u64::from(BPS_DENOMINATOR);
+    u32::try_from(boosted).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{vec, Env};
+
+    fn campaign(env: &Env, id: u32, event_type: u32, multiplier_bps: u32) -> BonusCampaign {
+        BonusCampaign {
+            id,
+            event_types: vec![env, event_type],
+            multiplier_bps,
+            start_time: 100,
+            end_time: 200,
+            started: false,
+        }
+    }
+
+    #[test]
+    fn test_invalid_campaigns() {
+        let env = Env::default();
+        let mut invalid = campaign(&env, 1, 0, 10_000);
+        assert!(!is_valid_campaign(&invalid));
+
+        invalid.multiplier_bps = MAX_MULTIPLIER_BPS + 1;
+        assert!(!is_valid_campaign(&invalid));
+
+        invalid.multiplier_bps = 20_000;
+        invalid.end_time = invalid.start_time;
+        assert!(!is_valid_campaign(&invalid));
+    }
+
+    #[test]
+    fn test_highest_running_multiplier_applies() {
+        let env = Env::default();
+        let mut campaigns = Map::new(&env);
+        campaigns.set(1, campaign(&env, 1, 0, 20_000));
+        campaigns.set(2, campaign(&env, 2, 0, 30_000));
+        campaigns.set(3, campaign(&env, 3, 3, 50_000));
+
+        assert_eq!(multiplier_for(&campaigns, 0, 99), BPS_DENOMINATOR);
+        assert_eq!(multiplier_for(&campaigns, 0, 100), 30_000);
+        assert_eq!(multiplier_for(&campaigns, 1, 150), BPS_DENOMINATOR);
+        assert_eq!(multiplier_for(&campaigns, 0, 200), BPS_DENOMINATOR);
+    }
+
+    #[test]
+    fn test_boosted_points() {
+        assert_eq!(boosted_points(10, 20_000), Some(20));
+        assert_eq!(boosted_points(15, 15_000), Some(22));
+        assert_eq!(boosted_points(u32::MAX, 20_000), None);
+    }
+}
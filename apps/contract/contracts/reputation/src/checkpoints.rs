@@ -0,0 +1,56 @@
+//! Checkpoint lookups.
+//!
+//! Each user has an append-only list of checkpoints with strictly increasing
+//! ledgers (a second change in the same ledger overwrites the last one), so
+//! the value at a past ledger is the last checkpoint at or before it.
+
+/// Find the index of the last checkpoint at or before `ledger`, given the
+/// number of checkpoints and a way to read a checkpoint's ledger. Reads
+/// O(log count) checkpoints.
+pub fn find_index(count: u32, ledger: u32, ledger_at: impl Fn(u32) -> u32) -> Option<u32> {
+    // Invariant: checkpoints before `low` are at or before `ledger`, and
+    // checkpoints from `high` on are after it
+    let mut low = 0;
+    let mut high = count;
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if ledger_at(mid) <= ledger {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    low.checked_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(ledgers: &[u32], ledger: u32) -> Option<u32> {
+        find_index(ledgers.len() as u32, ledger, |index| {
+            ledgers[index as usize]
+        })
+    }
+
+    #[test]
+    fn test_no_checkpoints() {
+        assert_eq!(find(&[], 100), None);
+    }
+
+    #[test]
+    fn test_before_first_checkpoint() {
+        assert_eq!(find(&[10, 20, 30], 9), None);
+    }
+
+    #[test]
+    fn test_exact_and_between_checkpoints() {
+        let ledgers = [10, 20, 30, 40, 50];
+        assert_eq!(find(&ledgers, 10), Some(0));
+        assert_eq!(find(&ledgers, 29), Some(1));
+        assert_eq!(find(&ledgers, 30), Some(2));
+        assert_eq!(find(&ledgers, 1000), Some(4));
+    }
+}
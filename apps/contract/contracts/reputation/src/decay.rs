@@ -0,0 +1,108 @@
+//! Time-based reputation decay.
+//!
+//! Points decay by `decay_bps` for every full `period_seconds` elapsed since
+//! the user's last event that awarded points, compounding. A policy with
+//! `decay_bps = 5000` is a half-life of `period_seconds`.
+//!
+//! Decay is lazy: reads return decayed values without touching storage, and
+//! writes persist the decayed balance before applying the new change, moving
+//! the user's last activity past the periods already applied.
+use crate::types::DecayPolicy;
+
+/// Basis points denominator (10000 = 100%).
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Check that a decay policy is usable.
+pub fn is_valid_policy(policy: &DecayPolicy) -> bool {
+    policy.period_seconds > 0 && policy.decay_bps <= BPS_DENOMINATOR
+}
+
+/// Compute the points left after decaying `points` over `elapsed` seconds.
+pub fn decayed_points(points: u32, policy: &DecayPolicy, elapsed: u64) -> u32 {
+    if points == 0 || policy.decay_bps == 0 || policy.period_seconds == 0 {
+        return points;
+    }
+
+    let periods = elapsed / policy.period_seconds;
+    let factor = retained_factor(BPS_DENOMINATOR - policy.decay_bps, periods);
+
+    // factor <= BPS_DENOMINATOR, so the result never exceeds `points`
+    (u64::from(points) * factor / u64::from(BPS_DENOMINATOR)) as u32
+}
+
+/// Move a last activity timestamp forward by the full periods elapsed until
+/// `now`, i.e. past the decay `decayed_points` applies over that time.
+pub fn advance_last_activity(last_activity: u64, policy: &DecayPolicy, now: u64) -> u64 {
+    if policy.period_seconds == 0 {
+        return last_activity;
+    }
+    let elapsed = now.saturating_sub(last_activity);
+    last_activity + (elapsed - elapsed % policy.period_seconds)
+}
+
+/// Compute (keep_bps / 10000)^periods, scaled to basis points, by repeated
+/// squaring. Rounds down at each step.
+fn retained_factor(keep_bps: u32, mut periods: u64) -> u64 {
+    let denominator = u64::from(BPS_DENOMINATOR);
+    let mut result = denominator;
+    let mut base = u64::from(keep_bps);
+
+    while periods > 0 && result > 0 {
+        if periods & 1 == 1 {
+            result = result * base / denominator;
+        }
+        base = base * base / denominator;
+        periods >>= 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(period_seconds: u64, decay_bps: u32) -> DecayPolicy {
+        DecayPolicy {
+            period_seconds,
+            decay_bps,
+        }
+    }
+
+    #[test]
+    fn test_no_decay_within_first_period() {
+        assert_eq!(decayed_points(1000, &policy(100, 1000), 99), 1000);
+    }
+
+    #[test]
+    fn test_decay_compounds_per_period() {
+        // 10% per period: 1000 -> 900 -> 810
+        assert_eq!(decayed_points(1000, &policy(100, 1000), 100), 900);
+        assert_eq!(decayed_points(1000, &policy(100, 1000), 250), 810);
+    }
+
+    #[test]
+    fn test_half_life() {
+        assert_eq!(decayed_points(1000, &policy(100, 5000), 300), 125);
+    }
+
+    #[test]
+    fn test_full_decay_and_long_inactivity() {
+        assert_eq!(decayed_points(1000, &policy(100, 10_000), 100), 0);
+        assert_eq!(decayed_points(u32::MAX, &policy(1, 100), u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_advance_last_activity_keeps_partial_period() {
+        assert_eq!(advance_last_activity(1000, &policy(100, 1000), 1250), 1200);
+        assert_eq!(advance_last_activity(1000, &policy(100, 1000), 1099), 1000);
+
+        // Decaying in two steps matches decaying at once
+        let persisted = decayed_points(1000, &policy(100, 1000), 250);
+        let elapsed = 400 - advance_last_activity(0, &policy(100, 1000), 250);
+        assert_eq!(
+            decayed_points(persisted, &policy(100, 1000), elapsed),
+            decayed_points(1000, &policy(100, 1000), 400)
+        );
+    }
+}
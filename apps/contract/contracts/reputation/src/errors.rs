@@ -12,13 +12,14 @@ pub enum Error {
     Unauthorized = 401,
     /// User not found in the reputation system
     UserNotFound = 402,
-    /// Invalid event type provided
+    /// Event type is not in the registry
     InvalidEventType = 403,
     /// Invalid points value (must be positive)
     InvalidPoints = 404,
     /// Points calculation would overflow
     PointsOverflow = 405,
-    /// Invalid level threshold configuration
+    /// Level table is empty, too long, does not start at 0 points, has
+    /// duplicate ids or thresholds that are not strictly increasing
     InvalidLevelThreshold = 406,
     /// NFT contract address not configured
     NFTContractNotSet = 407,
@@ -26,4 +27,55 @@ pub enum Error {
     NFTUpgradeFailed = 408,
     /// User does not have an NFT registered
     UserHasNoNFT = 409,
+    /// Decay policy has a zero period or more than 100% decay
+    InvalidDecayPolicy = 410,
+    /// External reference was already used for a different event
+    DuplicateReference = 411,
+    /// History retention must keep at least one record
+    InvalidHistoryRetention = 412,
+    /// Batch has more entries than allowed
+    BatchTooLarge = 413,
+    /// Level id is not in the level table
+    UnknownLevel = 414,
+    /// Event type is disabled
+    EventTypeDisabled = 415,
+    /// Event type was recorded for the user too recently
+    EventCooldownActive = 416,
+    /// Registry already holds the maximum number of custom event types
+    TooManyEventTypes = 417,
+    /// Event exceeds the event type's per-user rate limit
+    RateLimitExceeded = 418,
+    /// Event exceeds the recorder's daily points budget
+    RecorderBudgetExceeded = 419,
+    /// Rate limit has a zero period
+    InvalidRateLimit = 420,
+    /// A season is already active
+    SeasonAlreadyActive = 421,
+    /// No season is active
+    NoActiveSeason = 422,
+    /// Season id does not exist
+    UnknownSeason = 423,
+    /// Ledger is not in the past
+    LedgerNotFinalized = 424,
+    /// Leaderboard size is 0 or above the maximum
+    InvalidLeaderboardSize = 425,
+    /// Bonus campaign has no event types, a multiplier outside (1x, 10x] or
+    /// an empty time window
+    InvalidBonusCampaign = 426,
+    /// Maximum number of scheduled and running bonus campaigns reached
+    TooManyBonusCampaigns = 427,
+    /// Bonus campaign id does not exist
+    UnknownBonusCampaign = 428,
+    /// Source and target accounts of a migration or merge are the same
+    SameAccount = 429,
+    /// Migration target already has reputation
+    AccountNotEmpty = 430,
+    /// No merge is proposed for the account
+    UnknownMergeProposal = 431,
+    /// Merge time lock has not passed yet
+    MergeTimeLocked = 432,
+    /// No attester key is configured
+    AttesterNotSet = 433,
+    /// Attestation validity is 0 or above the maximum
+    InvalidAttestationValidity = 436,
 }
@@ -1,6 +1,9 @@
-use soroban_sdk::{contractevent, Address};
+use soroban_sdk::{contractevent, Address, BytesN, String, Vec};
 
-use crate::types::{EventType, Level};
+use crate::types::{
+    BonusCampaign, DecayPolicy, DeductionReason, EventTypeConfig, LevelDefinition, LevelTemplate,
+    LimitMode, RateLimit,
+};
 
 /// Event data emitted when a reputation event is recorded.
 #[contractevent]
@@ -9,10 +12,12 @@ pub struct ReputationEventData {
     /// User who received the points
     #[topic]
     pub user: Address,
-    /// Type of event
-    pub event_type: EventType,
+    /// Event type id
+    pub event_type: u32,
     /// Points awarded
     pub points: u32,
+    /// Points before bonus campaign multipliers and limits
+    pub base_points: u32,
     /// New total points after this event
     pub new_total_points: u32,
 }
@@ -24,14 +29,49 @@ pub struct LevelUpEventData {
     /// User who leveled up
     #[topic]
     pub user: Address,
-    /// Previous level
-    pub old_level: Level,
-    /// New level achieved
-    pub new_level: Level,
+    /// Previous level id
+    pub old_level: u32,
+    /// New level id achieved
+    pub new_level: u32,
     /// Total points at time of level up
     pub total_points: u32,
 }
 
+/// Event data emitted when a user's level drops after a deduction.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelDownEventData {
+    /// User whose level dropped
+    #[topic]
+    pub user: Address,
+    /// Previous level id
+    pub old_level: u32,
+    /// New, lower level id
+    pub new_level: u32,
+    /// Total points after the deduction
+    pub total_points: u32,
+}
+
+/// Event data emitted when points are deducted from a user.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointsDeductedEventData {
+    /// User who lost the points
+    #[topic]
+    pub user: Address,
+    /// Moderator who made the deduction
+    #[topic]
+    pub moderator: Address,
+    /// Event type id the deduction relates to
+    pub event_type: u32,
+    /// Reason code
+    pub reason: DeductionReason,
+    /// Points actually deducted (capped at the user's balance)
+    pub points: u32,
+    /// New total points after the deduction
+    pub new_total_points: u32,
+}
+
 /// Event data emitted when a user's NFT is upgraded.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -41,8 +81,8 @@ pub struct NFTUpgradedEventData {
     pub user: Address,
     /// Token ID that was upgraded
     pub token_id: u32,
-    /// New level reflected in the NFT
-    pub new_level: Level,
+    /// New level id reflected in the NFT
+    pub new_level: u32,
 }
 
 /// Event data emitted when level thresholds are updated.
@@ -56,6 +96,17 @@ pub struct ThresholdsUpdatedData {
     pub timestamp: u64,
 }
 
+/// Event data emitted when the level table is replaced.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelTableUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// New level table, ordered by threshold
+    pub levels: Vec<LevelDefinition>,
+}
+
 /// Event data emitted when event point values are updated.
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -88,3 +139,358 @@ pub struct UserNFTRegisteredData {
     /// Token ID registered
     pub token_id: u32,
 }
+
+/// Event data emitted when a user's NFT token ID mapping is cleared.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserNFTUnregisteredData {
+    /// User whose NFT mapping was cleared
+    #[topic]
+    pub user: Address,
+    /// Token ID that was unregistered
+    pub token_id: u32,
+}
+
+/// Event data emitted when a badge NFT is minted for a user.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NFTMintedEventData {
+    /// User who received the badge
+    #[topic]
+    pub user: Address,
+    /// Token ID of the minted badge
+    pub token_id: u32,
+    /// Level id the badge was minted at
+    pub level: u32,
+}
+
+/// Event data emitted when automatic badge minting is toggled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoMintUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// Whether automatic minting is enabled
+    pub enabled: bool,
+}
+
+/// Event data emitted when a level's badge template is set.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelTemplateUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// Level id the template applies to
+    pub level: u32,
+    /// New template
+    pub template: LevelTemplate,
+}
+
+/// Event data emitted when pending decay is applied to a user's points.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointsDecayedEventData {
+    /// User whose points decayed
+    #[topic]
+    pub user: Address,
+    /// Points lost to decay
+    pub points: u32,
+    /// Total points after decay
+    pub new_total_points: u32,
+}
+
+/// Event data emitted when the decay policy is set or removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayPolicyUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// New policy, or None if decay was disabled
+    pub policy: Option<DecayPolicy>,
+}
+
+/// Event data emitted when the history retention cap is updated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryRetentionUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// Number of history records kept per user
+    pub retention: u32,
+}
+
+/// Event data emitted when an event type is registered or updated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventTypeUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// Event type id
+    #[topic]
+    pub event_type: u32,
+    /// New registry entry
+    pub config: EventTypeConfig,
+}
+
+/// Event data emitted when an event is recorded with zero points because it
+/// exceeded a rate limit or the recorder's budget.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointsLimitedEventData {
+    /// User the event was recorded for
+    #[topic]
+    pub user: Address,
+    /// Recorder that made the call
+    #[topic]
+    pub recorder: Address,
+    /// Event type id
+    pub event_type: u32,
+    /// Points that would have been awarded
+    pub requested_points: u32,
+}
+
+/// Event data emitted when an event type's rate limit is set or removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// Event type id
+    #[topic]
+    pub event_type: u32,
+    /// New limit, or None if the event type is no longer limited
+    pub limit: Option<RateLimit>,
+}
+
+/// Event data emitted when the recorder budget is set or removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecorderBudgetUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// New daily points budget per recorder, or None if unlimited
+    pub budget: Option<u32>,
+}
+
+/// Event data emitted when the handling of events over a limit changes.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LimitModeUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// New mode
+    pub mode: LimitMode,
+}
+
+/// Event data emitted when a season starts.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonStartedEventData {
+    /// Admin who started the season
+    #[topic]
+    pub admin: Address,
+    /// Season id
+    #[topic]
+    pub season: u32,
+    /// Season name
+    pub name: String,
+    /// Start timestamp
+    pub start_time: u64,
+}
+
+/// Event data emitted when a season ends and its standings are finalized.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonEndedEventData {
+    /// Admin who ended the season
+    #[topic]
+    pub admin: Address,
+    /// Season id
+    #[topic]
+    pub season: u32,
+    /// End timestamp
+    pub end_time: u64,
+    /// Level table frozen for the season's standings
+    pub levels: Vec<LevelDefinition>,
+}
+
+/// Event data emitted when a user's season level changes.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonLevelChangedEventData {
+    /// User whose season level changed
+    #[topic]
+    pub user: Address,
+    /// Season id
+    #[topic]
+    pub season: u32,
+    /// Previous season level id
+    pub old_level: u32,
+    /// New season level id
+    pub new_level: u32,
+    /// User's season points after the change
+    pub season_points: u32,
+}
+
+/// Event data emitted when the leaderboard size is updated.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardSizeUpdatedData {
+    /// Admin who made the update
+    #[topic]
+    pub admin: Address,
+    /// Number of users kept on each leaderboard
+    pub size: u32,
+}
+
+/// Event data emitted when a bonus campaign is scheduled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BonusCampaignCreatedData {
+    /// Admin who created the campaign
+    #[topic]
+    pub admin: Address,
+    /// Scheduled campaign
+    pub campaign: BonusCampaign,
+}
+
+/// Event data emitted the first time an event is recorded after a bonus
+/// campaign started.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BonusCampaignStartedEventData {
+    /// Campaign id
+    #[topic]
+    pub campaign_id: u32,
+    /// Event type ids the multiplier applies to
+    pub event_types: Vec<u32>,
+    /// Multiplier in basis points
+    pub multiplier_bps: u32,
+    /// Timestamp the campaign ends at
+    pub end_time: u64,
+}
+
+/// Event data emitted the first time an event is recorded after a bonus
+/// campaign ended.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BonusCampaignEndedEventData {
+    /// Campaign id
+    #[topic]
+    pub campaign_id: u32,
+    /// Timestamp the campaign ended at
+    pub end_time: u64,
+}
+
+/// Event data emitted when a bonus campaign is cancelled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BonusCampaignCancelledData {
+    /// Admin who cancelled the campaign
+    #[topic]
+    pub admin: Address,
+    /// Campaign id
+    #[topic]
+    pub campaign_id: u32,
+}
+
+/// Event data emitted when updating a user's NFT fails. The user is queued
+/// until `sync_nft` succeeds.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NFTSyncFailedEventData {
+    /// User whose NFT is out of sync
+    #[topic]
+    pub user: Address,
+    /// NFT token id
+    pub token_id: u32,
+    /// Level id the NFT should show
+    pub level: u32,
+    /// Points the NFT should show
+    pub total_points: u32,
+}
+
+/// Event data emitted when a user's reputation is migrated to a new account.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserMigratedEventData {
+    /// Previous account
+    #[topic]
+    pub old_account: Address,
+    /// New account
+    #[topic]
+    pub new_account: Address,
+    /// Moderator who performed the migration
+    pub moderator: Address,
+    /// Points moved
+    pub points: u32,
+    /// Level id of the new account
+    pub level: u32,
+}
+
+/// Event data emitted when one account's reputation is merged into another.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UsersMergedEventData {
+    /// Account whose reputation was moved
+    #[topic]
+    pub source: Address,
+    /// Account that received the reputation
+    #[topic]
+    pub target: Address,
+    /// Moderator who proposed the merge, or None if both accounts authorized it
+    pub moderator: Option<Address>,
+    /// Points moved from the source
+    pub points: u32,
+    /// Target's total points after the merge
+    pub new_total_points: u32,
+}
+
+/// Event data emitted when a moderator proposes a merge.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeProposedEventData {
+    /// Account whose reputation would be moved
+    #[topic]
+    pub source: Address,
+    /// Account that would receive the reputation
+    #[topic]
+    pub target: Address,
+    /// Moderator who proposed the merge
+    pub moderator: Address,
+    /// Timestamp from which the merge can be executed
+    pub executable_at: u64,
+}
+
+/// Event data emitted when a proposed merge is cancelled.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeCancelledEventData {
+    /// Account whose reputation would have been moved
+    #[topic]
+    pub source: Address,
+    /// Account that would have received the reputation
+    #[topic]
+    pub target: Address,
+    /// Address that cancelled the merge
+    pub cancelled_by: Address,
+}
+
+/// Event data emitted when the attester key is set or removed.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttesterUpdatedData {
+    /// Admin who made the change
+    #[topic]
+    pub admin: Address,
+    /// New attester key, or None if removed
+    pub public_key: Option<BytesN<32>>,
+}
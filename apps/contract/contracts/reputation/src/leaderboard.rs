@@ -0,0 +1,135 @@
+//! Bounded top-N leaderboards.
+//!
+//! A board is a list of entries sorted by points, highest first, holding at
+//! most `size` users. Users are repositioned when their total changes; among
+//! equal totals, the user who reached it first ranks higher. A user who
+//! drops below the board while it is full is removed, and the free spot is
+//! taken by the next user whose total changes.
+use soroban_sdk::{Address, Vec};
+
+use crate::types::LeaderboardEntry;
+
+/// Compute a board after a user's total changed to `points`.
+/// Returns None if the board is unchanged.
+pub fn update(
+    board: &Vec<LeaderboardEntry>,
+    user: &Address,
+    points: u32,
+    size: u32,
+) -> Option<Vec<LeaderboardEntry>> {
+    let mut updated = board.clone();
+    let current = board.iter().position(|entry| entry.user == *user);
+    if let Some(index) = current {
+        updated.remove(index as u32);
+    }
+
+    // On a full board, users off the board may have up to the last entry's
+    // points, so a user only stays or enters above that
+    let full = board.len() >= size;
+    let floor = board.last().map_or(0, |last| last.points);
+    let qualifies =
+        points > 0 && (!full || points > floor || (current.is_some() && points >= floor));
+    if !qualifies {
+        return current.map(|_| updated);
+    }
+
+    let index = updated
+        .iter()
+        .position(|entry| entry.points < points)
+        .map_or(updated.len(), |index| index as u32);
+    updated.insert(
+        index,
+        LeaderboardEntry {
+            user: user.clone(),
+            points,
+        },
+    );
+
+    Some(truncate(&updated, size))
+}
+
+/// Keep the first `size` entries of a board.
+pub fn truncate(board: &Vec<LeaderboardEntry>, size: u32) -> Vec<LeaderboardEntry> {
+    board.slice(0..board.len().min(size))
+}
+
+/// Get a user's 1-based rank on a board.
+pub fn rank(board: &Vec<LeaderboardEntry>, user: &Address) -> Option<u32> {
+    board
+        .iter()
+        .position(|entry| entry.user == *user)
+        .map(|index| index as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, vec, Env};
+
+    fn apply(board: &mut Vec<LeaderboardEntry>, user: &Address, points: u32, size: u32) {
+        if let Some(updated) = update(board, user, points, size) {
+            *board = updated;
+        }
+    }
+
+    fn points(env: &Env, board: &Vec<LeaderboardEntry>) -> Vec<u32> {
+        let mut points = Vec::new(env);
+        for entry in board.iter() {
+            points.push_back(entry.points);
+        }
+        points
+    }
+
+    #[test]
+    fn test_entries_are_sorted_and_bounded() {
+        let env = Env::default();
+        let (a, b, c, d) = (
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        );
+        let mut board = Vec::new(&env);
+
+        apply(&mut board, &a, 10, 3);
+        apply(&mut board, &b, 30, 3);
+        apply(&mut board, &c, 20, 3);
+        apply(&mut board, &d, 5, 3);
+        assert_eq!(points(&env, &board), vec![&env, 30, 20, 10]);
+
+        apply(&mut board, &d, 25, 3);
+        assert_eq!(points(&env, &board), vec![&env, 30, 25, 20]);
+        assert_eq!(rank(&board, &d), Some(2));
+        assert_eq!(rank(&board, &a), None);
+    }
+
+    #[test]
+    fn test_ties_keep_earlier_user_ahead() {
+        let env = Env::default();
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let mut board = Vec::new(&env);
+
+        apply(&mut board, &first, 10, 5);
+        apply(&mut board, &second, 10, 5);
+        assert_eq!(rank(&board, &first), Some(1));
+        assert_eq!(rank(&board, &second), Some(2));
+    }
+
+    #[test]
+    fn test_user_dropping_off_full_board_is_removed() {
+        let env = Env::default();
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let mut board = Vec::new(&env);
+
+        apply(&mut board, &a, 50, 2);
+        apply(&mut board, &b, 40, 2);
+        // Someone off the board may have up to 40 points
+        apply(&mut board, &a, 30, 2);
+        assert_eq!(points(&env, &board), vec![&env, 40]);
+
+        apply(&mut board, &b, 0, 2);
+        assert!(board.is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+//! Level table lookups.
+//!
+//! Levels are configuration data: an ordered list of (id, name, threshold).
+//! A valid table starts at 0 points and has strictly increasing thresholds,
+//! so every point total maps to exactly one level and thresholds give the
+//! order between levels.
+use soroban_sdk::Vec;
+
+use crate::types::{LevelDefinition, MAX_LEVELS};
+
+/// Check that a level table is usable.
+pub fn is_valid_table(levels: &Vec<LevelDefinition>) -> bool {
+    if levels.is_empty() || levels.len() > MAX_LEVELS {
+        return false;
+    }
+    if levels.get_unchecked(0).threshold != 0 {
+        return false;
+    }
+
+    for i in 1..levels.len() {
+        let level = levels.get_unchecked(i);
+        if level.threshold <= levels.get_unchecked(i - 1).threshold {
+            return false;
+        }
+        if find(&levels.slice(0..i), level.id).is_some() {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Get the base level (the one reached at 0 points).
+pub fn base_level(levels: &Vec<LevelDefinition>) -> LevelDefinition {
+    levels.get_unchecked(0)
+}
+
+/// Get the highest level whose threshold `points` reaches.
+pub fn level_for_points(levels: &Vec<LevelDefinition>, points: u32) -> LevelDefinition {
+    let mut current = base_level(levels);
+    for level in levels.iter() {
+        if points < level.threshold {
+            break;
+        }
+        current = level;
+    }
+    current
+}
+
+/// Find a level by id.
+pub fn find(levels: &Vec<LevelDefinition>, id: u32) -> Option<LevelDefinition> {
+    levels.iter().find(|level| level.id == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{Env, String};
+
+    fn table(env: &Env, thresholds: &[(u32, u32)]) -> Vec<LevelDefinition> {
+        let mut levels = Vec::new(env);
+        for (id, threshold) in thresholds {
+            levels.push_back(LevelDefinition {
+                id: *id,
+                name: String::from_str(env, "level"),
+                threshold: *threshold,
+            });
+        }
+        levels
+    }
+
+    #[test]
+    fn test_valid_table() {
+        let env = Env::default();
+        assert!(is_valid_table(&table(&env, &[(0, 0), (7, 100), (3, 250)])));
+    }
+
+    #[test]
+    fn test_invalid_tables() {
+        let env = Env::default();
+        // Empty, not starting at 0, not strictly increasing, duplicate id
+        assert!(!is_valid_table(&table(&env, &[])));
+        assert!(!is_valid_table(&table(&env, &[(0, 10), (1, 100)])));
+        assert!(!is_valid_table(&table(&env, &[(0, 0), (1, 100), (2, 100)])));
+        assert!(!is_valid_table(&table(&env, &[(0, 0), (1, 100), (0, 200)])));
+    }
+
+    #[test]
+    fn test_level_for_points() {
+        let env = Env::default();
+        let levels = table(&env, &[(0, 0), (7, 100), (3, 250)]);
+
+        assert_eq!(level_for_points(&levels, 0).id, 0);
+        assert_eq!(level_for_points(&levels, 99).id, 0);
+        assert_eq!(level_for_points(&levels, 100).id, 7);
+        assert_eq!(level_for_points(&levels, 1000).id, 3);
+    }
+}
@@ -1,12 +1,21 @@
 #![no_std]
 
+mod attestation;
+mod bonus;
+mod checkpoints;
+mod decay;
 mod errors;
 mod events;
+mod leaderboard;
+mod levels;
+mod limits;
 mod nft_client;
 mod storage;
 mod types;
 
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, Map, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 use stellar_access::access_control::{
     accept_admin_transfer as storage_accept_admin_transfer, get_admin as storage_get_admin,
     get_role_admin as storage_get_role_admin, get_role_member as storage_get_role_member,
@@ -20,16 +29,47 @@ use stellar_macros::only_role;
 
 use crate::errors::Error;
 use crate::events::{
-    LevelUpEventData, NFTContractSetEventData, NFTUpgradedEventData, PointValuesUpdatedData,
-    ReputationEventData, ThresholdsUpdatedData, UserNFTRegisteredData,
+    AttesterUpdatedData, AutoMintUpdatedData, BonusCampaignCancelledData, BonusCampaignCreatedData,
+    BonusCampaignEndedEventData, BonusCampaignStartedEventData, DecayPolicyUpdatedData,
+    EventTypeUpdatedData, HistoryRetentionUpdatedData, LeaderboardSizeUpdatedData,
+    LevelDownEventData, LevelTableUpdatedData, LevelTemplateUpdatedData, LevelUpEventData,
+    LimitModeUpdatedData, MergeCancelledEventData, MergeProposedEventData, NFTContractSetEventData,
+    NFTMintedEventData, NFTSyncFailedEventData, NFTUpgradedEventData, PointValuesUpdatedData,
+    PointsDecayedEventData, PointsDeductedEventData, PointsLimitedEventData, RateLimitUpdatedData,
+    RecorderBudgetUpdatedData, ReputationEventData, SeasonEndedEventData,
+    SeasonLevelChangedEventData, SeasonStartedEventData, ThresholdsUpdatedData,
+    UserMigratedEventData, UserNFTRegisteredData, UserNFTUnregisteredData, UsersMergedEventData,
 };
 use crate::storage::{
-    add_user_event, get_event_point_value, get_level_threshold, get_nft_contract,
-    get_permission_threshold, get_points, get_user_events, get_user_level, get_user_nft_token_id,
-    is_initialized, set_event_point_value, set_initialized, set_level_threshold, set_nft_contract,
-    set_permission_threshold, set_points, set_user_level, set_user_nft_token_id,
+    add_checkpoint, add_pending_sync, add_user_event, fold_user_events, get_attester,
+    get_bonus_campaigns, get_checkpoint_at, get_current_season_id, get_custom_event_types,
+    get_decay_policy, get_event_history_index, get_event_type_config, get_external_ref,
+    get_history_retention, get_last_activity, get_last_event_of_type, get_leaderboard,
+    get_leaderboard_size, get_level_table, get_level_template, get_limit_mode, get_merge_proposal,
+    get_nft_contract, get_pending_sync_count, get_pending_syncs, get_permission_threshold,
+    get_points, get_rate_limit, get_rate_limit_usage, get_recorder_budget, get_recorder_usage,
+    get_season, get_season_leaderboard, get_season_level_table, get_season_points, get_user_event,
+    get_user_event_count, get_user_event_summary, get_user_events, get_user_level,
+    get_user_nft_token_id, is_auto_mint_enabled, is_initialized, is_sync_pending, leaderboard_page,
+    move_user_events, next_bonus_campaign_id, remove_last_activity, remove_last_event_of_type,
+    remove_merge_proposal, remove_pending_sync, remove_rate_limit_usage, remove_user_nft_token_id,
+    set_attester, set_auto_mint_enabled, set_bonus_campaigns, set_current_season_id,
+    set_custom_event_types, set_decay_policy, set_event_type_config, set_external_ref,
+    set_history_retention, set_initialized, set_last_activity, set_last_event_of_type,
+    set_leaderboard, set_leaderboard_size, set_level_table, set_level_template, set_limit_mode,
+    set_merge_proposal, set_nft_contract, set_permission_threshold, set_points, set_rate_limit,
+    set_rate_limit_usage, set_recorder_budget, set_recorder_usage, set_season,
+    set_season_leaderboard, set_season_level_table, set_season_points, set_user_level,
+    set_user_nft_token_id,
+};
+use crate::types::{
+    Attestation, BonusCampaign, Checkpoint, DecayPolicy, DeductionReason, EventType,
+    EventTypeConfig, EventTypeSummary, ExternalRefRecord, LeaderboardEntry, LevelDefinition,
+    LevelTemplate, LimitMode, LimitUsage, MergeProposal, PointsBreakdown, RateAllowance, RateLimit,
+    ReputationEventRecord, Season, SeasonStanding, ThresholdType, MAX_ATTESTATION_VALIDITY,
+    MAX_BONUS_CAMPAIGNS, MAX_CUSTOM_EVENT_TYPES, MAX_LEADERBOARD_SIZE, MERGE_TIME_LOCK,
+    RECORDER_BUDGET_PERIOD,
 };
-use crate::types::{EventType, Level, ReputationEventRecord, ThresholdType};
 
 // ============================================================================
 // Constants
@@ -44,12 +84,18 @@ const INSTANCE_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 /// TTL threshold before extending (29 days)
 const INSTANCE_TTL_THRESHOLD: u32 = INSTANCE_TTL_AMOUNT - DAY_IN_LEDGERS;
 
+/// Maximum number of entries accepted by `record_events_batch`.
+const MAX_BATCH_SIZE: u32 = 50;
+
 /// Role identifier for addresses that can record reputation events.
 pub const RECORDER_ROLE: &str = "recorder";
 
 /// Role identifier for addresses that can update configuration.
 pub const CONFIG_ROLE: &str = "config";
 
+/// Role identifier for addresses that can deduct points.
+pub const MODERATOR_ROLE: &str = "moderator";
+
 // ============================================================================
 // Contract
 // ============================================================================
@@ -60,11 +106,21 @@ pub const CONFIG_ROLE: &str = "config";
 /// for automatic upgrades. Built on OpenZeppelin Stellar Contracts.
 ///
 /// Features:
-/// - Multiple event types with configurable point values
-/// - Five-tier level system (Rookie, Bronze, Silver, Gold, Diamond)
+/// - Registry of built-in and custom event types with points, enabled flag and cooldown
+/// - Per-user rate limits per event type and a daily points budget per recorder
+/// - Seasons with per-season points and finalized season standings
+/// - Per-user checkpoints of points and level for lookups at past ledgers
+/// - Bounded top-N leaderboards, lifetime and per season
+/// - Time-boxed bonus campaigns multiplying the points of some event types
+/// - Configurable level table (defaults to Rookie, Bronze, Silver, Gold, Diamond)
 /// - Permission thresholds for platform features
-/// - NFT integration for automatic metadata updates
-/// - Role-based access control (recorder, config)
+/// - NFT integration for automatic badge minting and metadata updates, retrying
+///   failed updates through `sync_nft`
+/// - Point deductions with reason codes and level downgrades
+/// - Account migration and merges, with a time lock for moderator merges
+/// - Ed25519-signed attestations of a user's level, verifiable by other contracts
+/// - Optional inactivity decay, applied lazily on read and write
+/// - Role-based access control (recorder, config, moderator)
 #[contract]
 pub struct Reputation;
 
@@ -108,25 +164,63 @@ impl Reputation {
     /// # Arguments
     /// * `caller` - Address initiating the record (must have recorder role)
     /// * `user` - Address receiving the reputation points
-    /// * `event_type` - Type of event being recorded
+    /// * `event_type` - Id of a registered event type (built-in or custom)
+    /// * `external_ref` - Optional reference of the action behind the event (e.g. donation
+    ///   tx hash). Retrying with the same reference returns the original result.
     ///
     /// # Returns
     /// The user's new total points
+    ///
+    /// # Errors
+    /// * `Error::DuplicateReference` - If the reference was used for a different event
+    /// * `Error::InvalidEventType` - If the event type is not registered
+    /// * `Error::EventTypeDisabled` - If the event type is disabled
+    /// * `Error::EventCooldownActive` - If the event type's cooldown has not elapsed
+    /// * `Error::RateLimitExceeded` - If the event exceeds the event type's rate limit
+    ///   (only in `LimitMode::Reject`; otherwise it is recorded with zero points)
+    /// * `Error::RecorderBudgetExceeded` - If the event exceeds the recorder's daily
+    ///   budget (same handling as rate limits)
     #[only_role(caller, "recorder")]
-    pub fn record_event(e: &Env, caller: Address, user: Address, event_type: EventType) -> u32 {
-        let points = get_event_point_value(e, event_type);
-        Self::record_event_internal(e, &user, event_type, points)
+    pub fn record_event(
+        e: &Env,
+        caller: Address,
+        user: Address,
+        event_type: u32,
+        external_ref: Option<BytesN<32>>,
+    ) -> u32 {
+        if let Some(new_total) =
+            Self::check_external_ref_internal(e, &external_ref, &user, event_type, None)
+        {
+            return new_total;
+        }
+
+        let base_points = Self::use_event_type_internal(e, &user, event_type).default_points;
+        let points = Self::apply_bonus_internal(e, event_type, base_points);
+        let points = Self::apply_limits_internal(e, &caller, &user, event_type, points);
+        Self::record_event_internal(
+            e,
+            &user,
+            event_type,
+            points,
+            base_points,
+            external_ref,
+            true,
+        )
     }
 
     /// Record a reputation event with custom points.
     ///
+    /// Bonus campaigns apply to the custom points as in `record_event`.
+    ///
     /// Requires the "recorder" role.
     ///
     /// # Arguments
     /// * `caller` - Address initiating the record (must have recorder role)
     /// * `user` - Address receiving the reputation points
-    /// * `event_type` - Type of event being recorded
+    /// * `event_type` - Id of a registered event type (built-in or custom)
     /// * `points` - Custom points to award
+    /// * `external_ref` - Optional reference of the action behind the event (e.g. donation
+    ///   tx hash). Retrying with the same reference returns the original result.
     ///
     /// # Returns
     /// The user's new total points
@@ -134,29 +228,285 @@ impl Reputation {
     /// # Errors
     /// * `Error::InvalidPoints` - If points is 0
     /// * `Error::PointsOverflow` - If adding points would overflow
+    /// * `Error::DuplicateReference` - If the reference was used for a different event
+    /// * `Error::InvalidEventType` - If the event type is not registered
+    /// * `Error::EventTypeDisabled` - If the event type is disabled
+    /// * `Error::EventCooldownActive` - If the event type's cooldown has not elapsed
+    /// * `Error::RateLimitExceeded`, `Error::RecorderBudgetExceeded` - As in `record_event`
     #[only_role(caller, "recorder")]
     pub fn record_event_with_points(
         e: &Env,
         caller: Address,
         user: Address,
-        event_type: EventType,
+        event_type: u32,
         points: u32,
+        external_ref: Option<BytesN<32>>,
     ) -> u32 {
         if points == 0 {
             panic_with_error!(e, Error::InvalidPoints);
         }
-        Self::record_event_internal(e, &user, event_type, points)
+        if let Some(new_total) =
+            Self::check_external_ref_internal(e, &external_ref, &user, event_type, Some(points))
+        {
+            return new_total;
+        }
+
+        Self::use_event_type_internal(e, &user, event_type);
+        let base_points = points;
+        let points = Self::apply_bonus_internal(e, event_type, base_points);
+        let points = Self::apply_limits_internal(e, &caller, &user, event_type, points);
+
+        Self::record_event_internal(
+            e,
+            &user,
+            event_type,
+            points,
+            base_points,
+            external_ref,
+            true,
+        )
+    }
+
+    /// Record many reputation events in one call, e.g. when replaying
+    /// donations after an outage.
+    ///
+    /// Each entry is applied like `record_event` (when points is None) or
+    /// `record_event_with_points`, emitting the same per-event and level
+    /// events. Each affected user's NFT is synced (or auto-minted) once, after
+    /// all entries are applied.
+    ///
+    /// Requires the "recorder" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the record (must have recorder role)
+    /// * `events` - Entries of (user, event type id, optional custom points)
+    ///
+    /// # Returns
+    /// Map of each affected user to their new total points
+    ///
+    /// # Errors
+    /// * `Error::BatchTooLarge` - If there are more than 50 entries
+    /// * `Error::InvalidPoints` - If an entry has custom points of 0
+    /// * `Error::PointsOverflow` - If adding points would overflow
+    /// * `Error::InvalidEventType`, `Error::EventTypeDisabled`,
+    ///   `Error::EventCooldownActive`, `Error::RateLimitExceeded`,
+    ///   `Error::RecorderBudgetExceeded` - As in `record_event`
+    #[only_role(caller, "recorder")]
+    pub fn record_events_batch(
+        e: &Env,
+        caller: Address,
+        events: Vec<(Address, u32, Option<u32>)>,
+    ) -> Map<Address, u32> {
+        if events.len() > MAX_BATCH_SIZE {
+            panic_with_error!(e, Error::BatchTooLarge);
+        }
+
+        let mut totals: Map<Address, u32> = Map::new(e);
+        // Level of each user before the batch
+        let mut initial_levels: Map<Address, u32> = Map::new(e);
+
+        for (user, event_type, points) in events.iter() {
+            let config = Self::use_event_type_internal(e, &user, event_type);
+            let base_points = match points {
+                Some(0) => panic_with_error!(e, Error::InvalidPoints),
+                Some(points) => points,
+                None => config.default_points,
+            };
+            let points = Self::apply_bonus_internal(e, event_type, base_points);
+            let points = Self::apply_limits_internal(e, &caller, &user, event_type, points);
+
+            if !initial_levels.contains_key(user.clone()) {
+                let level = Self::user_level_internal(e, &user);
+                initial_levels.set(user.clone(), level);
+            }
+
+            let new_total =
+                Self::record_event_internal(e, &user, event_type, points, base_points, None, false);
+            totals.set(user, new_total);
+        }
+
+        for (user, new_total) in totals.iter() {
+            let old_level = initial_levels.get(user.clone()).unwrap();
+            let minted = Self::try_auto_mint_internal(e, &user, new_total);
+            Self::sync_nft_internal(e, &user, old_level, new_total, !minted);
+        }
+
+        totals
+    }
+
+    /// Check whether an external reference was already recorded.
+    ///
+    /// Returns the original total if the same event is being retried, and
+    /// None if the reference is new. `points` is only compared when the
+    /// caller asked for custom points.
+    fn check_external_ref_internal(
+        e: &Env,
+        external_ref: &Option<BytesN<32>>,
+        user: &Address,
+        event_type: u32,
+        points: Option<u32>,
+    ) -> Option<u32> {
+        let record = get_external_ref(e, external_ref.as_ref()?)?;
+
+        let same_event = record.user == *user
+            && record.event_type == event_type
+            && points.is_none_or(|points| points == record.points);
+        if !same_event {
+            panic_with_error!(e, Error::DuplicateReference);
+        }
+
+        Some(record.new_total_points)
+    }
+
+    /// Check that an event type can be recorded for a user now, and start
+    /// its cooldown. Returns the event type's registry entry.
+    fn use_event_type_internal(e: &Env, user: &Address, event_type: u32) -> EventTypeConfig {
+        let Some(config) = get_event_type_config(e, event_type) else {
+            panic_with_error!(e, Error::InvalidEventType);
+        };
+        if !config.enabled {
+            panic_with_error!(e, Error::EventTypeDisabled);
+        }
+
+        if config.cooldown_seconds > 0 {
+            let now = e.ledger().timestamp();
+            if let Some(last) = get_last_event_of_type(e, user, event_type) {
+                if now < last.saturating_add(config.cooldown_seconds) {
+                    panic_with_error!(e, Error::EventCooldownActive);
+                }
+            }
+            set_last_event_of_type(e, user, event_type, now);
+        }
+
+        config
+    }
+
+    /// Apply the highest running bonus campaign multiplier for an event type
+    /// to its points.
+    fn apply_bonus_internal(e: &Env, event_type: u32, points: u32) -> u32 {
+        let now = e.ledger().timestamp();
+        let campaigns = Self::refresh_bonus_campaigns_internal(e, now);
+        let multiplier_bps = bonus::multiplier_for(&campaigns, event_type, now);
+
+        match bonus::boosted_points(points, multiplier_bps) {
+            Some(points) => points,
+            None => panic_with_error!(e, Error::PointsOverflow),
+        }
+    }
+
+    /// Announce bonus campaigns that have started and drop the ones that have
+    /// ended since the last check. Returns the remaining campaigns.
+    fn refresh_bonus_campaigns_internal(e: &Env, now: u64) -> Map<u32, BonusCampaign> {
+        let mut campaigns = get_bonus_campaigns(e);
+        let mut changed = false;
+
+        for (id, mut campaign) in campaigns.clone().iter() {
+            if !campaign.started && now >= campaign.start_time {
+                campaign.started = true;
+                campaigns.set(id, campaign.clone());
+                changed = true;
+
+                BonusCampaignStartedEventData {
+                    campaign_id: id,
+                    event_types: campaign.event_types.clone(),
+                    multiplier_bps: campaign.multiplier_bps,
+                    end_time: campaign.end_time,
+                }
+                .publish(e);
+            }
+            if now >= campaign.end_time {
+                campaigns.remove(id);
+                changed = true;
+
+                BonusCampaignEndedEventData {
+                    campaign_id: id,
+                    end_time: campaign.end_time,
+                }
+                .publish(e);
+            }
+        }
+
+        if changed {
+            set_bonus_campaigns(e, &campaigns);
+        }
+        campaigns
+    }
+
+    /// Count an event against the event type's rate limit and the recorder's
+    /// budget. Returns the points to award: `points`, or 0 if the event is over
+    /// a limit and the limit mode is `ZeroPoints`.
+    fn apply_limits_internal(
+        e: &Env,
+        recorder: &Address,
+        user: &Address,
+        event_type: u32,
+        points: u32,
+    ) -> u32 {
+        let now = e.ledger().timestamp();
+
+        let rate_limit = get_rate_limit(e, event_type).map(|limit| {
+            let usage = get_rate_limit_usage(e, user, event_type);
+            let usage = limits::current_usage(usage, limit.period_seconds, now);
+            (limit, usage)
+        });
+        let budget = get_recorder_budget(e).map(|budget| {
+            let usage = get_recorder_usage(e, recorder);
+            (budget, limits::current_usage(usage, RECORDER_BUDGET_PERIOD, now))
+        });
+
+        let over_error = if rate_limit
+            .as_ref()
+            .is_some_and(|(limit, usage)| !limits::allows(limit, usage, points))
+        {
+            Some(Error::RateLimitExceeded)
+        } else if budget
+            .as_ref()
+            .is_some_and(|(budget, usage)| !limits::within_budget(*budget, usage, points))
+        {
+            Some(Error::RecorderBudgetExceeded)
+        } else {
+            None
+        };
+
+        let awarded = match over_error {
+            None => points,
+            Some(error) => {
+                if get_limit_mode(e) == LimitMode::Reject {
+                    panic_with_error!(e, error);
+                }
+                PointsLimitedEventData {
+                    user: user.clone(),
+                    recorder: recorder.clone(),
+                    event_type,
+                    requested_points: points,
+                }
+                .publish(e);
+                0
+            }
+        };
+
+        if let Some((_, usage)) = rate_limit {
+            set_rate_limit_usage(e, user, event_type, &limits::add_usage(usage, awarded));
+        }
+        if let Some((_, usage)) = budget {
+            set_recorder_usage(e, recorder, &limits::add_usage(usage, awarded));
+        }
+
+        awarded
     }
 
     /// Internal function to record an event.
     fn record_event_internal(
         e: &Env,
         user: &Address,
-        event_type: EventType,
+        event_type: u32,
         points: u32,
+        base_points: u32,
+        external_ref: Option<BytesN<32>>,
+        sync_nft: bool,
     ) -> u32 {
-        // Get current points and check overflow
-        let current_points = get_points(e, user);
+        // Get current (decayed) points and check overflow
+        let current_points = Self::apply_decay_internal(e, user);
         let new_total = current_points.checked_add(points);
 
         let new_total = match new_total {
@@ -166,64 +516,359 @@ impl Reputation {
 
         // Update points
         set_points(e, user, new_total);
+        Self::update_season_points_internal(e, user, points, false);
+        Self::update_leaderboard_internal(e, user, new_total);
+
+        // Mint a base-level badge if the user has none yet, e.g. on their first
+        // event or when an earlier mint failed
+        let minted = sync_nft && Self::try_auto_mint_internal(e, user, new_total);
 
         // Record event in history
         let event_record = ReputationEventRecord {
             event_type,
             points,
+            base_points,
             timestamp: e.ledger().timestamp(),
+            deduction_reason: None,
+            external_ref: external_ref.clone(),
         };
         add_user_event(e, user, event_record);
+        // Only events that award points restart the inactivity clock
+        if points > 0 {
+            set_last_activity(e, user, e.ledger().timestamp());
+        }
+
+        if let Some(external_ref) = &external_ref {
+            set_external_ref(
+                e,
+                external_ref,
+                &ExternalRefRecord {
+                    user: user.clone(),
+                    event_type,
+                    points: base_points,
+                    new_total_points: new_total,
+                },
+            );
+        }
 
         // Emit reputation event
         ReputationEventData {
             user: user.clone(),
             event_type,
             points,
+            base_points,
+            new_total_points: new_total,
+        }
+        .publish(e);
+
+        if sync_nft {
+            // A freshly minted badge already carries the new points
+            Self::update_level_internal(e, user, new_total, !minted);
+        } else {
+            Self::apply_level_internal(e, user, new_total);
+        }
+
+        Self::extend_instance_ttl(e);
+        new_total
+    }
+
+    /// Deduct points from a user, e.g. for fraud or a refunded donation.
+    ///
+    /// The deduction is capped at the user's balance, recorded in their history
+    /// with its reason code, and the level is recomputed downward. Permission
+    /// thresholds reflect the new level immediately.
+    ///
+    /// Requires the "moderator" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the deduction (must have moderator role)
+    /// * `user` - Address losing the points
+    /// * `points` - Points to deduct
+    /// * `event_type` - Id of the event type the deduction relates to
+    /// * `reason` - Reason code recorded in history
+    ///
+    /// # Returns
+    /// The user's new total points
+    ///
+    /// # Errors
+    /// * `Error::InvalidPoints` - If points is 0
+    /// * `Error::InvalidEventType` - If the event type is not registered
+    #[only_role(caller, "moderator")]
+    pub fn deduct_points(
+        e: &Env,
+        caller: Address,
+        user: Address,
+        points: u32,
+        event_type: u32,
+        reason: DeductionReason,
+    ) -> u32 {
+        if points == 0 {
+            panic_with_error!(e, Error::InvalidPoints);
+        }
+        if get_event_type_config(e, event_type).is_none() {
+            panic_with_error!(e, Error::InvalidEventType);
+        }
+
+        let current_points = Self::apply_decay_internal(e, &user);
+        let new_total = current_points.saturating_sub(points);
+        let deducted = current_points - new_total;
+
+        set_points(e, &user, new_total);
+        Self::update_season_points_internal(e, &user, deducted, true);
+        Self::update_leaderboard_internal(e, &user, new_total);
+
+        add_user_event(
+            e,
+            &user,
+            ReputationEventRecord {
+                event_type,
+                points: deducted,
+                base_points: deducted,
+                timestamp: e.ledger().timestamp(),
+                deduction_reason: Some(reason),
+                external_ref: None,
+            },
+        );
+
+        PointsDeductedEventData {
+            user: user.clone(),
+            moderator: caller,
+            event_type,
+            reason,
+            points: deducted,
             new_total_points: new_total,
         }
         .publish(e);
 
-        // Check for level up
-        let old_level = get_user_level(e, user);
-        let new_level = Self::calculate_level_internal(e, new_total);
+        Self::update_level_internal(e, &user, new_total, true);
+
+        Self::extend_instance_ttl(e);
+        new_total
+    }
+
+    /// Get a user's stored and decayed points, without writing anything.
+    fn effective_points_internal(e: &Env, user: &Address) -> (u32, u32) {
+        let raw_points = get_points(e, user);
+
+        let effective_points = match (get_decay_policy(e), Self::last_activity_internal(e, user)) {
+            (Some(policy), Some(last_activity)) => {
+                let elapsed = e.ledger().timestamp().saturating_sub(last_activity);
+                decay::decayed_points(raw_points, &policy, elapsed)
+            }
+            _ => raw_points,
+        };
+
+        (raw_points, effective_points)
+    }
+
+    /// Get the timestamp a user's decay is counted from. Users whose last
+    /// activity predates its tracking fall back to their newest history
+    /// record, or to the newest rolled-up record once history is rolled up.
+    fn last_activity_internal(e: &Env, user: &Address) -> Option<u64> {
+        if let Some(last_activity) = get_last_activity(e, user) {
+            return Some(last_activity);
+        }
+
+        let index = get_event_history_index(e, user);
+        if index.next > index.first {
+            if let Some(record) = get_user_event(e, user, index.next - 1) {
+                return Some(record.timestamp);
+            }
+        }
+        get_user_event_summary(e, user)
+            .values()
+            .iter()
+            .map(|summary| summary.last_timestamp)
+            .max()
+    }
+
+    /// Get a user's level from the current level table and their points after
+    /// pending decay. The stored level only tracks the last level announced
+    /// in level events, so table changes apply without waiting for a write.
+    fn effective_level_internal(e: &Env, user: &Address) -> LevelDefinition {
+        let (_, effective_points) = Self::effective_points_internal(e, user);
+        levels::level_for_points(&get_level_table(e), effective_points)
+    }
+
+    /// Get a user's stored level id, or the base level if none is stored.
+    fn user_level_internal(e: &Env, user: &Address) -> u32 {
+        get_user_level(e, user)
+            .unwrap_or_else(|| levels::base_level(&get_level_table(e)).id)
+    }
+
+    /// Persist pending decay before a write. Returns the decayed points.
+    /// The level is recomputed by the caller once the new change is applied.
+    ///
+    /// The user's last activity moves past the periods applied, so the
+    /// persisted decay is not counted again while the clock keeps running.
+    fn apply_decay_internal(e: &Env, user: &Address) -> u32 {
+        let (raw_points, effective_points) = Self::effective_points_internal(e, user);
+
+        if effective_points < raw_points {
+            set_points(e, user, effective_points);
+            if let (Some(policy), Some(last_activity)) =
+                (get_decay_policy(e), Self::last_activity_internal(e, user))
+            {
+                let now = e.ledger().timestamp();
+                let advanced = decay::advance_last_activity(last_activity, &policy, now);
+                set_last_activity(e, user, advanced);
+            }
+
+            PointsDecayedEventData {
+                user: user.clone(),
+                points: raw_points - effective_points,
+                new_total_points: effective_points,
+            }
+            .publish(e);
+        }
+
+        effective_points
+    }
+
+    /// Recompute a user's level after a points change, emit level events and
+    /// sync the NFT. When the level is unchanged, the NFT points attribute is
+    /// refreshed only if `sync_points` is set.
+    fn update_level_internal(e: &Env, user: &Address, new_total: u32, sync_points: bool) {
+        let old_level = Self::apply_level_internal(e, user, new_total);
+        Self::sync_nft_internal(e, user, old_level, new_total, sync_points);
+    }
 
-        if new_level > old_level {
-            set_user_level(e, user, new_level);
+    /// Store the level for a user's new total, checkpoint both and emit level
+    /// events. Returns the previous level id. A previous level that is no
+    /// longer in the level table counts as below every remaining level.
+    fn apply_level_internal(e: &Env, user: &Address, new_total: u32) -> u32 {
+        let levels = get_level_table(e);
+        let old_level = Self::user_level_internal(e, user);
+        let new_level = levels::level_for_points(&levels, new_total);
+        add_checkpoint(e, user, new_total, new_level.id);
+
+        if new_level.id == old_level {
+            return old_level;
+        }
+        set_user_level(e, user, new_level.id);
 
-            // Emit level up event
+        let old_threshold = levels::find(&levels, old_level).map_or(0, |level| level.threshold);
+        if new_level.threshold >= old_threshold {
             LevelUpEventData {
                 user: user.clone(),
                 old_level,
-                new_level,
+                new_level: new_level.id,
                 total_points: new_total,
             }
             .publish(e);
-
-            // Try to upgrade NFT with new level and points
-            Self::try_upgrade_nft_internal(e, user, new_level, new_total);
         } else {
+            LevelDownEventData {
+                user: user.clone(),
+                old_level,
+                new_level: new_level.id,
+                total_points: new_total,
+            }
+            .publish(e);
+        }
+
+        old_level
+    }
+
+    /// Bring a user's NFT in line with their stored level and points.
+    /// When the level is unchanged since `old_level`, the NFT points
+    /// attribute is refreshed only if `sync_points` is set.
+    fn sync_nft_internal(e: &Env, user: &Address, old_level: u32, total: u32, sync_points: bool) {
+        let levels = get_level_table(e);
+        let Some(level) = levels::find(&levels, Self::user_level_internal(e, user)) else {
+            return;
+        };
+
+        if level.id != old_level {
+            // Upgrade or downgrade the NFT (and its template, if any)
+            Self::try_upgrade_nft_internal(e, user, &level, total);
+        } else if sync_points {
             // Even if level didn't change, update NFT points if user has an NFT
             // This ensures points attribute stays current
             if let Some(nft_contract) = get_nft_contract(e) {
                 if let Some(token_id) = get_user_nft_token_id(e, user) {
                     let reputation_contract = e.current_contract_address();
-                    
-                    // Update NFT points even if level didn't change
-                    let _ = nft_client::try_upgrade_nft(
+
+                    let success = nft_client::try_upgrade_nft(
                         e,
                         &nft_contract,
                         &reputation_contract,
+                        user,
                         token_id,
-                        old_level, // Keep same level
-                        new_total, // Update points
+                        &level, // Keep same level
+                        total,  // Update points
                     );
+                    Self::track_nft_sync_internal(e, user, token_id, &level, total, success);
                 }
             }
         }
+    }
+
+    /// Add points to (or deduct them from) the user's total in the active
+    /// season, if any, and emit a season level event if it changes.
+    fn update_season_points_internal(e: &Env, user: &Address, points: u32, deduction: bool) {
+        let Some(season) = Self::active_season_internal(e) else {
+            return;
+        };
+        if points == 0 {
+            return;
+        }
 
-        Self::extend_instance_ttl(e);
-        new_total
+        let old_points = get_season_points(e, season.id, user);
+        let new_points = if deduction {
+            old_points.saturating_sub(points)
+        } else {
+            old_points.saturating_add(points)
+        };
+        set_season_points(e, season.id, user, new_points);
+
+        let board = get_season_leaderboard(e, season.id);
+        let size = get_leaderboard_size(e);
+        if let Some(board) = leaderboard::update(&board, user, new_points, size) {
+            set_season_leaderboard(e, season.id, &board);
+        }
+
+        let levels = get_level_table(e);
+        let old_level = levels::level_for_points(&levels, old_points);
+        let new_level = levels::level_for_points(&levels, new_points);
+        if new_level.id != old_level.id {
+            SeasonLevelChangedEventData {
+                user: user.clone(),
+                season: season.id,
+                old_level: old_level.id,
+                new_level: new_level.id,
+                season_points: new_points,
+            }
+            .publish(e);
+        }
+    }
+
+    /// Reposition a user on the lifetime leaderboard after their total changed.
+    fn update_leaderboard_internal(e: &Env, user: &Address, total: u32) {
+        let board = get_leaderboard(e);
+        if let Some(board) = leaderboard::update(&board, user, total, get_leaderboard_size(e)) {
+            set_leaderboard(e, &board);
+        }
+    }
+
+    /// Get a user's last checkpoint at or before a past ledger.
+    fn checkpoint_at_internal(e: &Env, user: &Address, ledger: u32) -> Option<Checkpoint> {
+        if ledger >= e.ledger().sequence() {
+            panic_with_error!(e, Error::LedgerNotFinalized);
+        }
+        get_checkpoint_at(e, user, ledger)
+    }
+
+    /// Get the active season, if any.
+    fn active_season_internal(e: &Env) -> Option<Season> {
+        get_season(e, get_current_season_id(e)).filter(|season| season.end_time.is_none())
+    }
+
+    /// Get a season, panicking if it does not exist.
+    fn season_internal(e: &Env, season_id: u32) -> Season {
+        match get_season(e, season_id) {
+            Some(season) => season,
+            None => panic_with_error!(e, Error::UnknownSeason),
+        }
     }
 
     // ========================================================================
@@ -236,12 +881,14 @@ impl Reputation {
     /// * `user` - Address to query
     ///
     /// # Returns
-    /// The user's level as u32 (0=Rookie, 1=Bronze, 2=Silver, 3=Gold, 4=Diamond)
+    /// The id of the user's level in the current level table, after pending
+    /// decay (the default table uses 0=Rookie, 1=Bronze, 2=Silver, 3=Gold,
+    /// 4=Diamond)
     pub fn get_level(e: &Env, user: Address) -> u32 {
-        get_user_level(e, &user).as_u32()
+        Self::effective_level_internal(e, &user).id
     }
 
-    /// Get a user's total points.
+    /// Get a user's total points, after pending decay.
     ///
     /// # Arguments
     /// * `user` - Address to query
@@ -249,7 +896,25 @@ impl Reputation {
     /// # Returns
     /// The user's total points
     pub fn get_points(e: &Env, user: Address) -> u32 {
-        get_points(e, &user)
+        Self::effective_points_internal(e, &user).1
+    }
+
+    /// Get a user's raw (stored) and effective (decayed) points.
+    ///
+    /// # Arguments
+    /// * `user` - Address to query
+    ///
+    /// # Returns
+    /// PointsBreakdown with raw, effective and decayed points and last activity
+    pub fn get_points_breakdown(e: &Env, user: Address) -> PointsBreakdown {
+        let (raw_points, effective_points) = Self::effective_points_internal(e, &user);
+
+        PointsBreakdown {
+            raw_points,
+            effective_points,
+            decayed_points: raw_points - effective_points,
+            last_activity: Self::last_activity_internal(e, &user),
+        }
     }
 
     /// Calculate what level a given point total would achieve.
@@ -258,29 +923,9 @@ impl Reputation {
     /// * `points` - Point total to evaluate
     ///
     /// # Returns
-    /// The level as u32
+    /// The level id
     pub fn calculate_level(e: &Env, points: u32) -> u32 {
-        Self::calculate_level_internal(e, points).as_u32()
-    }
-
-    /// Internal function to calculate level from points
-    fn calculate_level_internal(e: &Env, points: u32) -> Level {
-        let diamond_threshold = get_level_threshold(e, Level::Diamond);
-        let gold_threshold = get_level_threshold(e, Level::Gold);
-        let silver_threshold = get_level_threshold(e, Level::Silver);
-        let bronze_threshold = get_level_threshold(e, Level::Bronze);
-
-        if points >= diamond_threshold {
-            Level::Diamond
-        } else if points >= gold_threshold {
-            Level::Gold
-        } else if points >= silver_threshold {
-            Level::Silver
-        } else if points >= bronze_threshold {
-            Level::Bronze
-        } else {
-            Level::Rookie
-        }
+        levels::level_for_points(&get_level_table(e), points).id
     }
 
     /// Check if a user meets a permission threshold.
@@ -290,48 +935,113 @@ impl Reputation {
     /// * `threshold_type` - Type of threshold to check against
     ///
     /// # Returns
-    /// True if user's level meets or exceeds the threshold
+    /// True if user's level meets or exceeds the threshold, false if the
+    /// required level is no longer in the level table
     pub fn meets_threshold(e: &Env, user: Address, threshold_type: ThresholdType) -> bool {
-        let user_level = get_user_level(e, &user);
-        let required_level = get_permission_threshold(e, threshold_type);
-        user_level >= required_level
+        let user_level = Self::effective_level_internal(e, &user);
+        let required_id = get_permission_threshold(e, threshold_type);
+        match levels::find(&get_level_table(e), required_id) {
+            Some(required) => user_level.threshold >= required.threshold,
+            None => false,
+        }
     }
 
     /// Get the point threshold for a specific level.
     ///
     /// # Arguments
-    /// * `level` - Level to query (0-4)
+    /// * `level` - Level id to query
     ///
     /// # Returns
     /// Points required for that level
+    ///
+    /// # Errors
+    /// * `Error::UnknownLevel` - If the level is not in the level table
     pub fn get_level_threshold(e: &Env, level: u32) -> u32 {
-        let level_enum = Level::from_u32(level).unwrap_or(Level::Rookie);
-        get_level_threshold(e, level_enum)
+        match levels::find(&get_level_table(e), level) {
+            Some(level) => level.threshold,
+            None => panic_with_error!(e, Error::UnknownLevel),
+        }
+    }
+
+    /// Get the level table, ordered by threshold.
+    pub fn get_level_table(e: &Env) -> Vec<LevelDefinition> {
+        get_level_table(e)
     }
 
     /// Get the point value for a specific event type.
     ///
     /// # Arguments
-    /// * `event_type` - Event type to query
+    /// * `event_type` - Event type id to query
     ///
     /// # Returns
     /// Points awarded for that event type
-    pub fn get_event_point_value(e: &Env, event_type: EventType) -> u32 {
-        get_event_point_value(e, event_type)
+    ///
+    /// # Errors
+    /// * `Error::InvalidEventType` - If the event type is not registered
+    pub fn get_event_point_value(e: &Env, event_type: u32) -> u32 {
+        match get_event_type_config(e, event_type) {
+            Some(config) => config.default_points,
+            None => panic_with_error!(e, Error::InvalidEventType),
+        }
+    }
+
+    /// Get an event type's registry entry.
+    ///
+    /// # Arguments
+    /// * `event_type` - Event type id to query
+    ///
+    /// # Returns
+    /// The registry entry, or None if the event type is not registered
+    pub fn get_event_type(e: &Env, event_type: u32) -> Option<EventTypeConfig> {
+        get_event_type_config(e, event_type)
+    }
+
+    /// Get all registered event types, built-in and custom, keyed by id.
+    pub fn get_event_types(e: &Env) -> Map<u32, EventTypeConfig> {
+        let mut ids = get_custom_event_types(e);
+        for builtin in EventType::ALL {
+            ids.push_back(builtin.as_u32());
+        }
+
+        let mut event_types = Map::new(e);
+        for event_type in ids.iter() {
+            if let Some(config) = get_event_type_config(e, event_type) {
+                event_types.set(event_type, config);
+            }
+        }
+        event_types
     }
 
-    /// Get a user's event history.
+    /// Get a page of a user's event history, oldest first.
+    ///
+    /// Only the most recent records (see `get_history_retention`) are kept;
+    /// older ones are rolled into `get_user_event_summary`.
     ///
     /// # Arguments
     /// * `user` - Address to query
+    /// * `offset` - Index of the first event, counted over all events ever recorded
+    /// * `limit` - Maximum number of records to return (capped at 50)
     ///
     /// # Returns
-    /// Vector of ReputationEventRecord
+    /// Vector of ReputationEventRecord; rolled-up indexes are skipped
     pub fn get_user_events(
         e: &Env,
         user: Address,
-    ) -> soroban_sdk::Vec<ReputationEventRecord> {
-        get_user_events(e, &user)
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ReputationEventRecord> {
+        get_user_events(e, &user, offset, limit)
+    }
+
+    /// Get the total number of events recorded for a user, including
+    /// records that were rolled up.
+    pub fn get_user_event_count(e: &Env, user: Address) -> u32 {
+        get_user_event_count(e, &user)
+    }
+
+    /// Get per-event-type totals of a user's rolled-up history.
+    pub fn get_user_event_summary(e: &Env, user: Address) -> Map<u32, EventTypeSummary> {
+        get_user_event_summary(e, &user)
     }
 
     /// Get the NFT token ID registered for a user.
@@ -345,24 +1055,161 @@ impl Reputation {
         get_user_nft_token_id(e, &user)
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
-
-    /// Set level thresholds.
+    /// Get a user's points at the end of a past ledger.
     ///
-    /// Requires the "config" role.
+    /// Uses the checkpoints written whenever the user's total changes, so
+    /// other contracts can check eligibility against a snapshot (e.g. the
+    /// ledger a voting round opened at). Inactivity decay still pending at
+    /// that ledger is not reflected.
     ///
     /// # Arguments
-    /// * `caller` - Address initiating the update (must have config role)
-    /// * `thresholds` - Map of level (u32) to point threshold
-    #[only_role(caller, "config")]
-    pub fn set_level_thresholds(e: &Env, caller: Address, thresholds: Map<u32, u32>) {
-        for (level_u32, threshold) in thresholds.iter() {
-            if let Some(level) = Level::from_u32(level_u32) {
-                set_level_threshold(e, level, threshold);
-            }
+    /// * `user` - Address to query
+    /// * `ledger` - Ledger sequence, lower than the current one
+    ///
+    /// # Returns
+    /// The user's total points, or 0 if they had none yet
+    ///
+    /// # Errors
+    /// * `Error::LedgerNotFinalized` - If `ledger` is not in the past
+    pub fn get_points_at(e: &Env, user: Address, ledger: u32) -> u32 {
+        Self::checkpoint_at_internal(e, &user, ledger).map_or(0, |checkpoint| checkpoint.points)
+    }
+
+    /// Get a user's level id at the end of a past ledger.
+    ///
+    /// See `get_points_at`.
+    ///
+    /// # Returns
+    /// The user's level id, or the base level if they had no points yet
+    ///
+    /// # Errors
+    /// * `Error::LedgerNotFinalized` - If `ledger` is not in the past
+    pub fn get_level_at(e: &Env, user: Address, ledger: u32) -> u32 {
+        match Self::checkpoint_at_internal(e, &user, ledger) {
+            Some(checkpoint) => checkpoint.level,
+            None => levels::base_level(&get_level_table(e)).id,
+        }
+    }
+
+    /// Get a page of the lifetime leaderboard, highest points first.
+    ///
+    /// The board keeps the top users (100 by default, see
+    /// `set_leaderboard_size`) and is updated whenever a user's total
+    /// changes, so entries don't reflect inactivity decay that is still
+    /// pending.
+    ///
+    /// # Arguments
+    /// * `offset` - Index of the first entry (0 = top user)
+    /// * `limit` - Maximum entries to return (capped at 50)
+    pub fn get_leaderboard(e: &Env, offset: u32, limit: u32) -> Vec<LeaderboardEntry> {
+        leaderboard_page(&get_leaderboard(e), offset, limit)
+    }
+
+    /// Get a user's 1-based rank on the lifetime leaderboard.
+    ///
+    /// # Returns
+    /// The rank, or None if the user is not on the board
+    pub fn get_rank(e: &Env, user: Address) -> Option<u32> {
+        leaderboard::rank(&get_leaderboard(e), &user)
+    }
+
+    /// Get what a user can still receive for an event type in the current
+    /// rate limit window.
+    ///
+    /// # Arguments
+    /// * `user` - Address to query
+    /// * `event_type` - Event type id
+    ///
+    /// # Returns
+    /// The remaining allowance, or None if the event type has no rate limit
+    pub fn get_remaining_allowance(
+        e: &Env,
+        user: Address,
+        event_type: u32,
+    ) -> Option<RateAllowance> {
+        let limit = get_rate_limit(e, event_type)?;
+        let usage = get_rate_limit_usage(e, &user, event_type);
+        let usage = limits::current_usage(usage, limit.period_seconds, e.ledger().timestamp());
+        Some(limits::remaining(&limit, &usage))
+    }
+
+    /// Get the points a recorder can still award today.
+    ///
+    /// # Arguments
+    /// * `recorder` - Recorder address to query
+    ///
+    /// # Returns
+    /// The remaining budget, or None if recorders have no budget
+    pub fn get_recorder_budget_remaining(e: &Env, recorder: Address) -> Option<u32> {
+        let budget = get_recorder_budget(e)?;
+        let usage = get_recorder_usage(e, &recorder);
+        let usage = limits::current_usage(usage, RECORDER_BUDGET_PERIOD, e.ledger().timestamp());
+        Some(budget.saturating_sub(usage.points))
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Replace the level table.
+    ///
+    /// `get_level` and `meets_threshold` use the new table right away. Users'
+    /// stored levels, and the level events and NFT updates that follow from
+    /// them, catch up on their next event or deduction.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `levels` - Levels ordered by threshold; the first must start at 0 points
+    ///
+    /// # Errors
+    /// * `Error::InvalidLevelThreshold` - If the table is empty, has more than 20
+    ///   levels, does not start at 0, has duplicate ids or thresholds that are
+    ///   not strictly increasing
+    #[only_role(caller, "config")]
+    pub fn set_level_table(e: &Env, caller: Address, levels: Vec<LevelDefinition>) {
+        if !levels::is_valid_table(&levels) {
+            panic_with_error!(e, Error::InvalidLevelThreshold);
+        }
+        set_level_table(e, &levels);
+
+        LevelTableUpdatedData {
+            admin: caller,
+            levels,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set level thresholds.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `thresholds` - Map of level id to point threshold
+    ///
+    /// # Errors
+    /// * `Error::UnknownLevel` - If a level is not in the level table
+    /// * `Error::InvalidLevelThreshold` - If the thresholds would no longer be
+    ///   strictly increasing, or the base level would not start at 0
+    #[only_role(caller, "config")]
+    pub fn set_level_thresholds(e: &Env, caller: Address, thresholds: Map<u32, u32>) {
+        let mut levels = get_level_table(e);
+        for (id, threshold) in thresholds.iter() {
+            let Some(index) = levels.iter().position(|level| level.id == id) else {
+                panic_with_error!(e, Error::UnknownLevel);
+            };
+            let mut level = levels.get_unchecked(index as u32);
+            level.threshold = threshold;
+            levels.set(index as u32, level);
+        }
+        if !levels::is_valid_table(&levels) {
+            panic_with_error!(e, Error::InvalidLevelThreshold);
         }
+        set_level_table(e, &levels);
 
         ThresholdsUpdatedData {
             admin: caller.clone(),
@@ -379,15 +1226,22 @@ impl Reputation {
     ///
     /// # Arguments
     /// * `caller` - Address initiating the update (must have config role)
-    /// * `event_points` - Map of EventType to point value
+    /// * `event_points` - Map of event type id to point value
+    ///
+    /// # Errors
+    /// * `Error::InvalidEventType` - If an event type is not registered
     #[only_role(caller, "config")]
     pub fn set_event_point_values(
         e: &Env,
         caller: Address,
-        event_points: Map<EventType, u32>,
+        event_points: Map<u32, u32>,
     ) {
         for (event_type, points) in event_points.iter() {
-            set_event_point_value(e, event_type, points);
+            let Some(mut config) = get_event_type_config(e, event_type) else {
+                panic_with_error!(e, Error::InvalidEventType);
+            };
+            config.default_points = points;
+            set_event_type_config(e, event_type, &config);
         }
 
         PointValuesUpdatedData {
@@ -399,6 +1253,40 @@ impl Reputation {
         Self::extend_instance_ttl(e);
     }
 
+    /// Register a custom event type, or update any event type's registry
+    /// entry (including built-ins, e.g. to disable one or add a cooldown).
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `event_type` - Event type id; ids 0-7 are the built-in types
+    /// * `config` - Name, default points, enabled flag and per-user cooldown
+    ///
+    /// # Errors
+    /// * `Error::TooManyEventTypes` - If registering a new custom type would exceed 32
+    #[only_role(caller, "config")]
+    pub fn set_event_type(e: &Env, caller: Address, event_type: u32, config: EventTypeConfig) {
+        if get_event_type_config(e, event_type).is_none() {
+            let mut custom_types = get_custom_event_types(e);
+            if custom_types.len() >= MAX_CUSTOM_EVENT_TYPES {
+                panic_with_error!(e, Error::TooManyEventTypes);
+            }
+            custom_types.push_back(event_type);
+            set_custom_event_types(e, &custom_types);
+        }
+        set_event_type_config(e, event_type, &config);
+
+        EventTypeUpdatedData {
+            admin: caller,
+            event_type,
+            config,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
     /// Set a permission threshold.
     ///
     /// Requires the "config" role.
@@ -406,7 +1294,10 @@ impl Reputation {
     /// # Arguments
     /// * `caller` - Address initiating the update (must have config role)
     /// * `threshold_type` - Type of threshold to set
-    /// * `level` - Required level (0-4)
+    /// * `level` - Required level id
+    ///
+    /// # Errors
+    /// * `Error::UnknownLevel` - If the level is not in the level table
     #[only_role(caller, "config")]
     pub fn set_permission_threshold(
         e: &Env,
@@ -414,8 +1305,10 @@ impl Reputation {
         threshold_type: ThresholdType,
         level: u32,
     ) {
-        let level_enum = Level::from_u32(level).unwrap_or(Level::Rookie);
-        set_permission_threshold(e, threshold_type, level_enum);
+        if levels::find(&get_level_table(e), level).is_none() {
+            panic_with_error!(e, Error::UnknownLevel);
+        }
+        set_permission_threshold(e, threshold_type, level);
         Self::extend_instance_ttl(e);
     }
 
@@ -444,6 +1337,283 @@ impl Reputation {
         Self::extend_instance_ttl(e);
     }
 
+    /// Enable or disable automatic badge minting on events of users without
+    /// an NFT.
+    ///
+    /// Requires the "config" role. Minting also needs an NFT contract, a
+    /// template for the Rookie level, and the `minter` role for this contract
+    /// on the NFT contract. A mint that fails is tried again on the user's
+    /// next event.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `enabled` - Whether to mint badges automatically
+    #[only_role(caller, "config")]
+    pub fn set_auto_mint(e: &Env, caller: Address, enabled: bool) {
+        set_auto_mint_enabled(e, enabled);
+
+        AutoMintUpdatedData {
+            admin: caller,
+            enabled,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set the badge template (name, description, image) for a level.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `level` - Level id the template applies to
+    /// * `template` - Badge template
+    ///
+    /// # Errors
+    /// * `Error::UnknownLevel` - If the level is not in the level table
+    #[only_role(caller, "config")]
+    pub fn set_level_template(e: &Env, caller: Address, level: u32, template: LevelTemplate) {
+        if levels::find(&get_level_table(e), level).is_none() {
+            panic_with_error!(e, Error::UnknownLevel);
+        }
+        set_level_template(e, level, &template);
+
+        LevelTemplateUpdatedData {
+            admin: caller,
+            level,
+            template,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set or remove the inactivity decay policy.
+    ///
+    /// Points lose `decay_bps` for every full `period_seconds` since the user's
+    /// last recorded event, compounding. Use `decay_bps = 5000` for a half-life.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `policy` - New policy, or None to disable decay
+    ///
+    /// # Errors
+    /// * `Error::InvalidDecayPolicy` - If the period is 0 or decay exceeds 10000 bps
+    #[only_role(caller, "config")]
+    pub fn set_decay_policy(e: &Env, caller: Address, policy: Option<DecayPolicy>) {
+        if let Some(policy) = &policy {
+            if !decay::is_valid_policy(policy) {
+                panic_with_error!(e, Error::InvalidDecayPolicy);
+            }
+        }
+        set_decay_policy(e, &policy);
+
+        DecayPolicyUpdatedData {
+            admin: caller,
+            policy,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set how many history records are kept per user.
+    ///
+    /// Older records are rolled into the per-event-type summary as new events
+    /// arrive. Lowering the cap trims existing histories gradually, a few
+    /// records per write.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `retention` - Number of records to keep per user
+    ///
+    /// # Errors
+    /// * `Error::InvalidHistoryRetention` - If retention is 0
+    #[only_role(caller, "config")]
+    pub fn set_history_retention(e: &Env, caller: Address, retention: u32) {
+        if retention == 0 {
+            panic_with_error!(e, Error::InvalidHistoryRetention);
+        }
+        set_history_retention(e, retention);
+
+        HistoryRetentionUpdatedData {
+            admin: caller,
+            retention,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set or remove the per-user rate limit of an event type.
+    ///
+    /// Usage is counted in fixed windows of `period_seconds`, and only from
+    /// events recorded while a limit is set.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `event_type` - Event type id
+    /// * `limit` - New limit, or None to remove it
+    ///
+    /// # Errors
+    /// * `Error::InvalidEventType` - If the event type is not registered
+    /// * `Error::InvalidRateLimit` - If the period is 0
+    #[only_role(caller, "config")]
+    pub fn set_rate_limit(e: &Env, caller: Address, event_type: u32, limit: Option<RateLimit>) {
+        if get_event_type_config(e, event_type).is_none() {
+            panic_with_error!(e, Error::InvalidEventType);
+        }
+        if let Some(limit) = &limit {
+            if !limits::is_valid_limit(limit) {
+                panic_with_error!(e, Error::InvalidRateLimit);
+            }
+        }
+        set_rate_limit(e, event_type, &limit);
+
+        RateLimitUpdatedData {
+            admin: caller,
+            event_type,
+            limit,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set or remove the daily points budget of each recorder.
+    ///
+    /// The budget applies to every recorder separately, across all users and
+    /// event types, and resets every day (UTC).
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `budget` - Points each recorder can award per day, or None for no budget
+    #[only_role(caller, "config")]
+    pub fn set_recorder_budget(e: &Env, caller: Address, budget: Option<u32>) {
+        set_recorder_budget(e, budget);
+
+        RecorderBudgetUpdatedData {
+            admin: caller,
+            budget,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set how events over a rate limit or recorder budget are handled.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `mode` - `Reject` to fail the call, `ZeroPoints` to record the event
+    ///   with zero points
+    #[only_role(caller, "config")]
+    pub fn set_limit_mode(e: &Env, caller: Address, mode: LimitMode) {
+        set_limit_mode(e, mode);
+
+        LimitModeUpdatedData {
+            admin: caller,
+            mode,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Set how many users are kept on each leaderboard.
+    ///
+    /// Shrinking drops the lowest entries of the lifetime and active season
+    /// boards right away; growing lets more users on as their totals change.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `size` - Number of users per board
+    ///
+    /// # Errors
+    /// * `Error::InvalidLeaderboardSize` - If size is 0 or above 200
+    #[only_role(caller, "config")]
+    pub fn set_leaderboard_size(e: &Env, caller: Address, size: u32) {
+        if size == 0 || size > MAX_LEADERBOARD_SIZE {
+            panic_with_error!(e, Error::InvalidLeaderboardSize);
+        }
+        set_leaderboard_size(e, size);
+
+        let board = get_leaderboard(e);
+        if board.len() > size {
+            set_leaderboard(e, &leaderboard::truncate(&board, size));
+        }
+        if let Some(season) = Self::active_season_internal(e) {
+            let board = get_season_leaderboard(e, season.id);
+            if board.len() > size {
+                set_season_leaderboard(e, season.id, &leaderboard::truncate(&board, size));
+            }
+        }
+
+        LeaderboardSizeUpdatedData {
+            admin: caller,
+            size,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get how many users are kept on each leaderboard.
+    pub fn get_leaderboard_size(e: &Env) -> u32 {
+        get_leaderboard_size(e)
+    }
+
+    /// Get how many history records are kept per user.
+    pub fn get_history_retention(e: &Env) -> u32 {
+        get_history_retention(e)
+    }
+
+    /// Get the per-user rate limit of an event type.
+    pub fn get_rate_limit(e: &Env, event_type: u32) -> Option<RateLimit> {
+        get_rate_limit(e, event_type)
+    }
+
+    /// Get the daily points budget of each recorder.
+    pub fn get_recorder_budget(e: &Env) -> Option<u32> {
+        get_recorder_budget(e)
+    }
+
+    /// Get how events over a rate limit or recorder budget are handled.
+    pub fn get_limit_mode(e: &Env) -> LimitMode {
+        get_limit_mode(e)
+    }
+
+    /// Get the inactivity decay policy.
+    pub fn get_decay_policy(e: &Env) -> Option<DecayPolicy> {
+        get_decay_policy(e)
+    }
+
+    /// Check whether badges are minted automatically for users without an NFT.
+    pub fn is_auto_mint_enabled(e: &Env) -> bool {
+        is_auto_mint_enabled(e)
+    }
+
+    /// Get the badge template for a level.
+    ///
+    /// # Arguments
+    /// * `level` - Level id to query
+    pub fn get_level_template(e: &Env, level: u32) -> Option<LevelTemplate> {
+        get_level_template(e, level)
+    }
+
     /// Register a user's NFT token ID.
     ///
     /// Requires the "recorder" role.
@@ -466,47 +1636,1001 @@ impl Reputation {
     }
 
     // ========================================================================
-    // NFT Integration
+    // Season Functions
     // ========================================================================
 
-    /// Try to upgrade the user's NFT with the new level and points.
-    /// Gracefully handles cases where NFT is not configured or user has no NFT.
-    fn try_upgrade_nft_internal(e: &Env, user: &Address, new_level: Level, total_points: u32) {
-        // Check if NFT contract is configured
+    /// Start a new season. Points recorded or deducted while it is active are
+    /// also tracked per season, alongside lifetime points.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `name` - Season display name (e.g. "Season 3")
+    ///
+    /// # Returns
+    /// The new season's id (seasons are numbered from 1)
+    ///
+    /// # Errors
+    /// * `Error::SeasonAlreadyActive` - If the previous season has not ended
+    #[only_role(caller, "config")]
+    pub fn start_season(e: &Env, caller: Address, name: String) -> u32 {
+        if Self::active_season_internal(e).is_some() {
+            panic_with_error!(e, Error::SeasonAlreadyActive);
+        }
+
+        let season = Season {
+            id: get_current_season_id(e) + 1,
+            name,
+            start_time: e.ledger().timestamp(),
+            end_time: None,
+        };
+        set_season(e, &season);
+        set_current_season_id(e, season.id);
+
+        SeasonStartedEventData {
+            admin: caller,
+            season: season.id,
+            name: season.name,
+            start_time: season.start_time,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+        season.id
+    }
+
+    /// End the active season and finalize its standings.
+    ///
+    /// Season points stop changing, and the current level table is frozen
+    /// for the season so later table changes don't alter its levels.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    ///
+    /// # Returns
+    /// The id of the ended season
+    ///
+    /// # Errors
+    /// * `Error::NoActiveSeason` - If no season is active
+    #[only_role(caller, "config")]
+    pub fn end_season(e: &Env, caller: Address) -> u32 {
+        let Some(mut season) = Self::active_season_internal(e) else {
+            panic_with_error!(e, Error::NoActiveSeason);
+        };
+
+        let end_time = e.ledger().timestamp();
+        season.end_time = Some(end_time);
+        set_season(e, &season);
+
+        let levels = get_level_table(e);
+        set_season_level_table(e, season.id, &levels);
+
+        SeasonEndedEventData {
+            admin: caller,
+            season: season.id,
+            end_time,
+            levels,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+        season.id
+    }
+
+    /// Get the active season.
+    ///
+    /// # Returns
+    /// The active season, or None between seasons
+    pub fn get_active_season(e: &Env) -> Option<Season> {
+        Self::active_season_internal(e)
+    }
+
+    /// Get a season by id.
+    pub fn get_season(e: &Env, season_id: u32) -> Option<Season> {
+        get_season(e, season_id)
+    }
+
+    /// Get the id of the latest season (0 before the first season).
+    pub fn get_current_season_id(e: &Env) -> u32 {
+        get_current_season_id(e)
+    }
+
+    /// Get a user's points in a season.
+    ///
+    /// # Errors
+    /// * `Error::UnknownSeason` - If the season does not exist
+    pub fn get_season_points(e: &Env, season_id: u32, user: Address) -> u32 {
+        Self::season_internal(e, season_id);
+        get_season_points(e, season_id, &user)
+    }
+
+    /// Get the level table used for a season's standings: the table frozen at
+    /// season end, or the current table while the season is active.
+    ///
+    /// # Errors
+    /// * `Error::UnknownSeason` - If the season does not exist
+    pub fn get_season_level_table(e: &Env, season_id: u32) -> Vec<LevelDefinition> {
+        Self::season_internal(e, season_id);
+        get_season_level_table(e, season_id).unwrap_or_else(|| get_level_table(e))
+    }
+
+    /// Get a user's standing in a season: season points and the level they
+    /// reach in the season's level table (e.g. "Season 3 Gold").
+    ///
+    /// # Errors
+    /// * `Error::UnknownSeason` - If the season does not exist
+    pub fn get_season_standing(e: &Env, season_id: u32, user: Address) -> SeasonStanding {
+        let season = Self::season_internal(e, season_id);
+        let points = get_season_points(e, season_id, &user);
+        let levels = get_season_level_table(e, season_id).unwrap_or_else(|| get_level_table(e));
+
+        SeasonStanding {
+            season: season_id,
+            points,
+            level: levels::level_for_points(&levels, points),
+            finalized: season.end_time.is_some(),
+        }
+    }
+
+    /// Get a page of a season's leaderboard, highest season points first.
+    ///
+    /// # Arguments
+    /// * `season_id` - Season id
+    /// * `offset` - Index of the first entry (0 = top user)
+    /// * `limit` - Maximum entries to return (capped at 50)
+    ///
+    /// # Errors
+    /// * `Error::UnknownSeason` - If the season does not exist
+    pub fn get_season_leaderboard(
+        e: &Env,
+        season_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<LeaderboardEntry> {
+        Self::season_internal(e, season_id);
+        leaderboard_page(&get_season_leaderboard(e, season_id), offset, limit)
+    }
+
+    /// Get a user's 1-based rank on a season's leaderboard.
+    ///
+    /// # Returns
+    /// The rank, or None if the user is not on the board
+    ///
+    /// # Errors
+    /// * `Error::UnknownSeason` - If the season does not exist
+    pub fn get_season_rank(e: &Env, season_id: u32, user: Address) -> Option<u32> {
+        Self::season_internal(e, season_id);
+        leaderboard::rank(&get_season_leaderboard(e, season_id), &user)
+    }
+
+    // ========================================================================
+    // Bonus Campaign Functions
+    // ========================================================================
+
+    /// Schedule a bonus campaign multiplying the points of some event types
+    /// between `start_time` and `end_time`.
+    ///
+    /// When several campaigns cover the same event, only the highest
+    /// multiplier applies. Start and end events are emitted with the first
+    /// recorded event after each of them, or by `refresh_bonus_campaigns`.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `event_types` - Ids of the event types the multiplier applies to
+    /// * `multiplier_bps` - Multiplier in basis points (20000 = 2x)
+    /// * `start_time` - Timestamp the campaign starts at
+    /// * `end_time` - Timestamp the campaign ends at (exclusive)
+    ///
+    /// # Returns
+    /// The new campaign's id
+    ///
+    /// # Errors
+    /// * `Error::InvalidBonusCampaign` - If there are no event types, the multiplier
+    ///   is not above 1x or is above 10x, or the campaign ends before it starts or
+    ///   has already ended
+    /// * `Error::InvalidEventType` - If an event type is not registered
+    /// * `Error::TooManyBonusCampaigns` - If 10 campaigns are already scheduled or running
+    #[only_role(caller, "config")]
+    pub fn create_bonus_campaign(
+        e: &Env,
+        caller: Address,
+        event_types: Vec<u32>,
+        multiplier_bps: u32,
+        start_time: u64,
+        end_time: u64,
+    ) -> u32 {
+        let now = e.ledger().timestamp();
+        let mut campaign = BonusCampaign {
+            id: 0,
+            event_types,
+            multiplier_bps,
+            start_time,
+            end_time,
+            started: false,
+        };
+        if !bonus::is_valid_campaign(&campaign) || end_time <= now {
+            panic_with_error!(e, Error::InvalidBonusCampaign);
+        }
+        for event_type in campaign.event_types.iter() {
+            if get_event_type_config(e, event_type).is_none() {
+                panic_with_error!(e, Error::InvalidEventType);
+            }
+        }
+
+        let mut campaigns = Self::refresh_bonus_campaigns_internal(e, now);
+        if campaigns.len() >= MAX_BONUS_CAMPAIGNS {
+            panic_with_error!(e, Error::TooManyBonusCampaigns);
+        }
+        campaign.id = next_bonus_campaign_id(e);
+        campaigns.set(campaign.id, campaign.clone());
+        set_bonus_campaigns(e, &campaigns);
+
+        BonusCampaignCreatedData {
+            admin: caller,
+            campaign: campaign.clone(),
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+        campaign.id
+    }
+
+    /// Cancel a scheduled or running bonus campaign.
+    ///
+    /// Requires the "config" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the update (must have config role)
+    /// * `campaign_id` - Campaign id
+    ///
+    /// # Errors
+    /// * `Error::UnknownBonusCampaign` - If the campaign does not exist or has ended
+    #[only_role(caller, "config")]
+    pub fn cancel_bonus_campaign(e: &Env, caller: Address, campaign_id: u32) {
+        let mut campaigns = Self::refresh_bonus_campaigns_internal(e, e.ledger().timestamp());
+        if campaigns.get(campaign_id).is_none() {
+            panic_with_error!(e, Error::UnknownBonusCampaign);
+        }
+        campaigns.remove(campaign_id);
+        set_bonus_campaigns(e, &campaigns);
+
+        BonusCampaignCancelledData {
+            admin: caller,
+            campaign_id,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Announce bonus campaigns that have started and drop the ones that have
+    /// ended, emitting their start and end events without waiting for the
+    /// next recorded event.
+    ///
+    /// Can be called by anyone, e.g. a keeper running at campaign boundaries.
+    pub fn refresh_bonus_campaigns(e: &Env) {
+        Self::refresh_bonus_campaigns_internal(e, e.ledger().timestamp());
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the scheduled and running bonus campaigns, by id.
+    pub fn get_bonus_campaigns(e: &Env) -> Vec<BonusCampaign> {
+        let now = e.ledger().timestamp();
+        let mut campaigns = Vec::new(e);
+        for campaign in get_bonus_campaigns(e).values().iter() {
+            if now < campaign.end_time {
+                campaigns.push_back(campaign);
+            }
+        }
+        campaigns
+    }
+
+    /// Get a scheduled or running bonus campaign.
+    ///
+    /// # Arguments
+    /// * `campaign_id` - Campaign id
+    pub fn get_bonus_campaign(e: &Env, campaign_id: u32) -> Option<BonusCampaign> {
+        get_bonus_campaigns(e)
+            .get(campaign_id)
+            .filter(|campaign| e.ledger().timestamp() < campaign.end_time)
+    }
+
+    /// Get the multiplier currently applied to an event type, in basis points
+    /// (10000 if no campaign is running for it).
+    ///
+    /// # Arguments
+    /// * `event_type` - Event type id
+    pub fn get_event_multiplier(e: &Env, event_type: u32) -> u32 {
+        bonus::multiplier_for(&get_bonus_campaigns(e), event_type, e.ledger().timestamp())
+    }
+
+    // ========================================================================
+    // Account Migration Functions
+    // ========================================================================
+
+    /// Move a user's reputation to a new account, e.g. a smart account
+    /// recovered after the old wallet was lost.
+    ///
+    /// Points, level, event history, cooldowns, rate limit usage, points in
+    /// the active season and leaderboard entries all move to `new`, and `old`
+    /// is left at zero points. Ended seasons keep their standings.
+    /// Checkpoints are not moved: both accounts
+    /// get a checkpoint at the current ledger, so lookups at earlier ledgers
+    /// still report what each account held then. The NFT binding follows the
+    /// token on the NFT contract: it stays with `old` until `old` transfers
+    /// the token, and moves to `new` (queued for `sync_nft`) through
+    /// `on_nft_transfer` when it is transferred there.
+    ///
+    /// Requires the "moderator" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address initiating the migration (must have moderator role)
+    /// * `old` - Account to migrate from
+    /// * `new` - Account to migrate to, without any reputation yet
+    ///
+    /// # Returns
+    /// The new account's total points
+    ///
+    /// # Errors
+    /// * `Error::SameAccount` - If `old` and `new` are the same
+    /// * `Error::AccountNotEmpty` - If `new` already has points, events or an NFT
+    #[only_role(caller, "moderator")]
+    pub fn migrate_user(e: &Env, caller: Address, old: Address, new: Address) -> u32 {
+        if old == new {
+            panic_with_error!(e, Error::SameAccount);
+        }
+        if get_points(e, &new) > 0
+            || get_user_event_count(e, &new) > 0
+            || get_user_nft_token_id(e, &new).is_some()
+        {
+            panic_with_error!(e, Error::AccountNotEmpty);
+        }
+
+        let (points, new_total) = Self::merge_accounts_internal(e, &old, &new);
+
+        UserMigratedEventData {
+            old_account: old,
+            new_account: new.clone(),
+            moderator: caller,
+            points,
+            level: Self::user_level_internal(e, &new),
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+        new_total
+    }
+
+    /// Merge one account's reputation into another, with the authorization of
+    /// both accounts.
+    ///
+    /// Points, points in the active season, cooldowns and rate limit usage
+    /// are summed into `target`. If `target` has no history yet, `source`'s history moves as
+    /// in `migrate_user`; otherwise it is rolled into `target`'s event
+    /// summary. NFT bindings stay with the token owners, as in `migrate_user`.
+    /// Without `source`'s authorization, use `propose_merge`.
+    ///
+    /// # Arguments
+    /// * `source` - Account whose reputation is moved
+    /// * `target` - Account receiving the reputation
+    ///
+    /// # Returns
+    /// The target's total points
+    ///
+    /// # Errors
+    /// * `Error::SameAccount` - If `source` and `target` are the same
+    /// * `Error::PointsOverflow` - If the sum would overflow
+    pub fn merge_users(e: &Env, source: Address, target: Address) -> u32 {
+        source.require_auth();
+        target.require_auth();
+        if source == target {
+            panic_with_error!(e, Error::SameAccount);
+        }
+
+        let new_total = Self::merge_users_internal(e, &source, &target, None);
+        Self::extend_instance_ttl(e);
+        new_total
+    }
+
+    /// Propose merging one account into another without its authorization.
+    ///
+    /// The merge can be executed with `execute_merge` three days later, which
+    /// leaves time for the owner of either account to `cancel_merge`.
+    /// Proposing again for the same source replaces the proposal and restarts
+    /// the time lock.
+    ///
+    /// Requires the "moderator" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address proposing the merge (must have moderator role)
+    /// * `source` - Account whose reputation would be moved
+    /// * `target` - Account that would receive the reputation
+    ///
+    /// # Returns
+    /// The timestamp from which the merge can be executed
+    ///
+    /// # Errors
+    /// * `Error::SameAccount` - If `source` and `target` are the same
+    #[only_role(caller, "moderator")]
+    pub fn propose_merge(e: &Env, caller: Address, source: Address, target: Address) -> u64 {
+        if source == target {
+            panic_with_error!(e, Error::SameAccount);
+        }
+
+        let executable_at = e.ledger().timestamp().saturating_add(MERGE_TIME_LOCK);
+        set_merge_proposal(
+            e,
+            &MergeProposal {
+                source: source.clone(),
+                target: target.clone(),
+                proposer: caller.clone(),
+                executable_at,
+            },
+        );
+
+        MergeProposedEventData {
+            source,
+            target,
+            moderator: caller,
+            executable_at,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+        executable_at
+    }
+
+    /// Execute a proposed merge once its time lock has passed.
+    ///
+    /// Requires the "moderator" role.
+    ///
+    /// # Arguments
+    /// * `caller` - Address executing the merge (must have moderator role)
+    /// * `source` - Account whose merge was proposed
+    ///
+    /// # Returns
+    /// The target's total points
+    ///
+    /// # Errors
+    /// * `Error::UnknownMergeProposal` - If no merge is proposed for `source`
+    /// * `Error::MergeTimeLocked` - If the time lock has not passed yet
+    /// * `Error::PointsOverflow` - If the sum would overflow
+    #[only_role(caller, "moderator")]
+    pub fn execute_merge(e: &Env, caller: Address, source: Address) -> u32 {
+        let Some(proposal) = get_merge_proposal(e, &source) else {
+            panic_with_error!(e, Error::UnknownMergeProposal);
+        };
+        if e.ledger().timestamp() < proposal.executable_at {
+            panic_with_error!(e, Error::MergeTimeLocked);
+        }
+        remove_merge_proposal(e, &source);
+
+        let new_total =
+            Self::merge_users_internal(e, &source, &proposal.target, Some(proposal.proposer));
+        Self::extend_instance_ttl(e);
+        new_total
+    }
+
+    /// Cancel a proposed merge.
+    ///
+    /// Can be called by either account of the merge or by a moderator.
+    ///
+    /// # Arguments
+    /// * `caller` - Address cancelling the merge
+    /// * `source` - Account whose merge was proposed
+    ///
+    /// # Errors
+    /// * `Error::UnknownMergeProposal` - If no merge is proposed for `source`
+    /// * `Error::Unauthorized` - If the caller is not an account of the merge
+    ///   or a moderator
+    pub fn cancel_merge(e: &Env, caller: Address, source: Address) {
+        caller.require_auth();
+        let Some(proposal) = get_merge_proposal(e, &source) else {
+            panic_with_error!(e, Error::UnknownMergeProposal);
+        };
+        let is_party = caller == proposal.source || caller == proposal.target;
+        if !is_party && storage_has_role(e, &caller, &Self::moderator_role(e)).is_none() {
+            panic_with_error!(e, Error::Unauthorized);
+        }
+        remove_merge_proposal(e, &source);
+
+        MergeCancelledEventData {
+            source,
+            target: proposal.target,
+            cancelled_by: caller,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the pending merge proposal of an account, if any.
+    ///
+    /// # Arguments
+    /// * `source` - Account whose reputation would be moved
+    pub fn get_merge_proposal(e: &Env, source: Address) -> Option<MergeProposal> {
+        get_merge_proposal(e, &source)
+    }
+
+    /// Merge two accounts and emit the audit event. Returns the target's new
+    /// total.
+    fn merge_users_internal(
+        e: &Env,
+        source: &Address,
+        target: &Address,
+        moderator: Option<Address>,
+    ) -> u32 {
+        let (points, new_total) = Self::merge_accounts_internal(e, source, target);
+
+        UsersMergedEventData {
+            source: source.clone(),
+            target: target.clone(),
+            moderator,
+            points,
+            new_total_points: new_total,
+        }
+        .publish(e);
+
+        new_total
+    }
+
+    /// Move all of `source`'s reputation state into `target`, summing where
+    /// both have some. Returns the points moved and the target's new total.
+    fn merge_accounts_internal(e: &Env, source: &Address, target: &Address) -> (u32, u32) {
+        // Persist pending decay on both sides before summing
+        let points = Self::apply_decay_internal(e, source);
+        let target_points = Self::apply_decay_internal(e, target);
+        let Some(new_total) = target_points.checked_add(points) else {
+            panic_with_error!(e, Error::PointsOverflow);
+        };
+
+        if get_user_event_count(e, target) == 0 {
+            move_user_events(e, source, target);
+        } else {
+            fold_user_events(e, source, target);
+        }
+        // Both balances are decayed up to now, so the target's inactivity
+        // clock restarts to not decay them again
+        remove_last_activity(e, source);
+        set_last_activity(e, target, e.ledger().timestamp());
+        Self::merge_event_type_state_internal(e, source, target);
+        Self::merge_season_points_internal(e, source, target);
+
+        set_points(e, source, 0);
+        set_points(e, target, new_total);
+        let board = leaderboard::update(&get_leaderboard(e), source, 0, get_leaderboard_size(e));
+        if let Some(board) = board {
+            set_leaderboard(e, &board);
+        }
+        Self::update_leaderboard_internal(e, target, new_total);
+
+        // The NFT binding stays with the token's owner; `on_nft_transfer`
+        // rebinds it once the source transfers the token
+
+        Self::apply_level_internal(e, source, 0);
+        Self::update_level_internal(e, target, new_total, true);
+
+        (points, new_total)
+    }
+
+    /// Move `source`'s cooldowns and rate limit usage to `target`, keeping
+    /// the later cooldown and summing usage of the same window.
+    fn merge_event_type_state_internal(e: &Env, source: &Address, target: &Address) {
+        let mut ids = get_custom_event_types(e);
+        for builtin in EventType::ALL {
+            ids.push_back(builtin.as_u32());
+        }
+
+        for event_type in ids.iter() {
+            if let Some(last) = get_last_event_of_type(e, source, event_type) {
+                let target_last = get_last_event_of_type(e, target, event_type).unwrap_or(0);
+                set_last_event_of_type(e, target, event_type, last.max(target_last));
+                remove_last_event_of_type(e, source, event_type);
+            }
+
+            let usage = get_rate_limit_usage(e, source, event_type);
+            if usage != LimitUsage::default() {
+                let target_usage = get_rate_limit_usage(e, target, event_type);
+                let combined = limits::combine(usage, target_usage);
+                set_rate_limit_usage(e, target, event_type, &combined);
+                remove_rate_limit_usage(e, source, event_type);
+            }
+        }
+    }
+
+    /// Move `source`'s points in the active season, if any, to `target`,
+    /// repositioning both on its leaderboard. Ended seasons keep the
+    /// standings they were finalized with.
+    fn merge_season_points_internal(e: &Env, source: &Address, target: &Address) {
+        let Some(season) = Self::active_season_internal(e) else {
+            return;
+        };
+        let points = get_season_points(e, season.id, source);
+        if points == 0 {
+            return;
+        }
+        let total = get_season_points(e, season.id, target).saturating_add(points);
+        set_season_points(e, season.id, source, 0);
+        set_season_points(e, season.id, target, total);
+
+        let size = get_leaderboard_size(e);
+        let mut board = get_season_leaderboard(e, season.id);
+        if let Some(updated) = leaderboard::update(&board, source, 0, size) {
+            board = updated;
+        }
+        if let Some(updated) = leaderboard::update(&board, target, total, size) {
+            board = updated;
+        }
+        set_season_leaderboard(e, season.id, &board);
+    }
+
+    // ========================================================================
+    // Attestation Functions
+    // ========================================================================
+
+    /// Set or remove the ed25519 public key that signs attestations.
+    ///
+    /// Only admin can call this function. Rotating the key invalidates every
+    /// attestation signed with the previous one.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin address
+    /// * `public_key` - Attester public key, or None to stop verifying
+    pub fn set_attester(e: &Env, caller: Address, public_key: Option<BytesN<32>>) {
+        let admin = storage_get_admin(e);
+        if admin.is_none() || admin.unwrap() != caller {
+            panic_with_error!(e, Error::Unauthorized);
+        }
+        caller.require_auth();
+
+        set_attester(e, &public_key);
+
+        AttesterUpdatedData {
+            admin: caller,
+            public_key,
+        }
+        .publish(e);
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Get the configured attester public key.
+    pub fn get_attester(e: &Env) -> Option<BytesN<32>> {
+        get_attester(e)
+    }
+
+    /// Build an unsigned attestation of a user's current level and points.
+    ///
+    /// The attester service signs `get_attestation_digest` of the result
+    /// off-chain and hands the attestation and signature to the user.
+    ///
+    /// # Arguments
+    /// * `user` - User to attest
+    /// * `valid_for` - Seconds the attestation stays valid
+    ///
+    /// # Errors
+    /// * `Error::AttesterNotSet` - If no attester key is configured
+    /// * `Error::InvalidAttestationValidity` - If `valid_for` is 0 or above 30 days
+    pub fn get_attestation(e: &Env, user: Address, valid_for: u64) -> Attestation {
+        let attester = match get_attester(e) {
+            Some(key) => key,
+            None => panic_with_error!(e, Error::AttesterNotSet),
+        };
+        if valid_for == 0 || valid_for > MAX_ATTESTATION_VALIDITY {
+            panic_with_error!(e, Error::InvalidAttestationValidity);
+        }
+
+        let level = Self::effective_level_internal(e, &user).id;
+        let (_, points) = Self::effective_points_internal(e, &user);
+
+        Attestation {
+            user,
+            level,
+            points,
+            ledger: e.ledger().sequence(),
+            expires_at: e.ledger().timestamp().saturating_add(valid_for),
+            attester,
+        }
+    }
+
+    /// Get the digest the attester signs for an attestation.
+    ///
+    /// This is the SHA-256 hash of the XDR encoding of
+    /// `(contract address, attestation)`.
+    pub fn get_attestation_digest(e: &Env, attestation: Attestation) -> BytesN<32> {
+        attestation::digest(e, &e.current_contract_address(), &attestation)
+    }
+
+    /// Verify an attestation signed by the attester key.
+    ///
+    /// Partner contracts call this and, if it returns true, trust the
+    /// attestation's fields, e.g. checking `attestation.level` against their
+    /// own requirement.
+    ///
+    /// The signature is checked last, by the host's ed25519 verification,
+    /// which aborts the call on an invalid signature instead of returning;
+    /// partners that must not abort can call this through
+    /// `try_invoke_contract`.
+    ///
+    /// # Arguments
+    /// * `attestation` - Attestation to verify
+    /// * `signature` - Ed25519 signature of the attestation digest
+    ///
+    /// # Returns
+    /// True if the attestation is valid, false if no attester key is
+    /// configured, the attestation names another key or it has expired
+    pub fn verify_attestation(e: &Env, attestation: Attestation, signature: BytesN<64>) -> bool {
+        let Some(attester) = get_attester(e) else {
+            return false;
+        };
+        if attestation.attester != attester
+            || attestation::is_expired(&attestation, e.ledger().timestamp())
+        {
+            return false;
+        }
+
+        let message: Bytes =
+            attestation::digest(e, &e.current_contract_address(), &attestation).into();
+        e.crypto().ed25519_verify(&attester, &message, &signature);
+        true
+    }
+
+    // ========================================================================
+    // NFT Integration
+    // ========================================================================
+
+    /// Transfer hook called by the NFT contract when a token changes owner.
+    ///
+    /// Clears `from`'s mapping if it points at the token, and binds the token
+    /// to `to` if `to` has no NFT registered yet. `to` is None when the token
+    /// was burned. The NFT metadata is not synced here, since calling back into
+    /// the NFT contract during its own transfer is not allowed; a newly bound
+    /// recipient is added to the pending syncs instead, so `sync_nft` can bring
+    /// the badge in line with their level and points.
+    ///
+    /// Only the configured NFT contract can call this function.
+    ///
+    /// # Arguments
+    /// * `token_id` - Token that changed owner
+    /// * `from` - Previous owner
+    /// * `to` - New owner, or None on burn
+    ///
+    /// # Errors
+    /// * `Error::NFTContractNotSet` - If no NFT contract is configured
+    pub fn on_nft_transfer(e: &Env, token_id: u32, from: Address, to: Option<Address>) {
+        let nft_contract = match get_nft_contract(e) {
+            Some(addr) => addr,
+            None => panic_with_error!(e, Error::NFTContractNotSet),
+        };
+        nft_contract.require_auth();
+
+        if get_user_nft_token_id(e, &from) == Some(token_id) {
+            remove_user_nft_token_id(e, &from);
+            remove_pending_sync(e, &from);
+
+            UserNFTUnregisteredData {
+                user: from,
+                token_id,
+            }
+            .publish(e);
+        }
+
+        if let Some(to) = to {
+            if get_user_nft_token_id(e, &to).is_none() {
+                set_user_nft_token_id(e, &to, token_id);
+                add_pending_sync(e, &to);
+
+                UserNFTRegisteredData {
+                    user: to,
+                    token_id,
+                }
+                .publish(e);
+            }
+        }
+
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Retry bringing a user's NFT in line with their stored level and points,
+    /// e.g. after the contract regained the `metadata_manager` role on the
+    /// NFT contract.
+    ///
+    /// Anyone can call this function. It clears the user from the pending
+    /// syncs on success, or if they no longer have an NFT registered.
+    ///
+    /// # Arguments
+    /// * `user` - User whose NFT to sync
+    ///
+    /// # Returns
+    /// True if the NFT was updated, false if the user has no NFT registered
+    ///
+    /// # Errors
+    /// * `Error::NFTContractNotSet` - If no NFT contract is configured
+    /// * `Error::NFTUpgradeFailed` - If the NFT contract rejected the update
+    pub fn sync_nft(e: &Env, user: Address) -> bool {
+        if get_nft_contract(e).is_none() {
+            panic_with_error!(e, Error::NFTContractNotSet);
+        }
+        if get_user_nft_token_id(e, &user).is_none() {
+            remove_pending_sync(e, &user);
+            return false;
+        }
+
+        let total = get_points(e, &user);
+        let levels = get_level_table(e);
+        let level = levels::find(&levels, Self::user_level_internal(e, &user))
+            .unwrap_or_else(|| levels::level_for_points(&levels, total));
+        if !Self::try_upgrade_nft_internal(e, &user, &level, total) {
+            panic_with_error!(e, Error::NFTUpgradeFailed);
+        }
+
+        Self::extend_instance_ttl(e);
+        true
+    }
+
+    /// Get a page of the users whose NFT failed to update and is waiting for
+    /// `sync_nft`. The order changes as syncs are cleared.
+    ///
+    /// # Arguments
+    /// * `offset` - Index of the first user to return
+    /// * `limit` - Maximum number of users to return (capped at 50)
+    pub fn get_pending_syncs(e: &Env, offset: u32, limit: u32) -> Vec<Address> {
+        get_pending_syncs(e, offset, limit)
+    }
+
+    /// Get the number of users whose NFT is waiting for `sync_nft`.
+    pub fn get_pending_sync_count(e: &Env) -> u32 {
+        get_pending_sync_count(e)
+    }
+
+    /// Check whether a user's NFT is waiting for `sync_nft`.
+    pub fn is_sync_pending(e: &Env, user: Address) -> bool {
+        is_sync_pending(e, &user)
+    }
+
+    /// Try to mint a base-level badge for the user and register its token ID.
+    /// Skips silently if auto-mint is disabled, no NFT contract or Rookie
+    /// template is configured, or the user already has an NFT.
+    ///
+    /// Returns true if a badge was minted.
+    fn try_auto_mint_internal(e: &Env, user: &Address, total_points: u32) -> bool {
+        if !is_auto_mint_enabled(e) || get_user_nft_token_id(e, user).is_some() {
+            return false;
+        }
+        let Some(nft_contract) = get_nft_contract(e) else {
+            return false;
+        };
+        let base_level = levels::base_level(&get_level_table(e));
+        let Some(template) = get_level_template(e, base_level.id) else {
+            return false;
+        };
+
+        // The reputation contract needs the minter role on the NFT contract
+        let reputation_contract = e.current_contract_address();
+        let metadata =
+            nft_client::build_level_metadata(e, &template, &base_level, total_points);
+
+        let Some(token_id) =
+            nft_client::try_mint_nft(e, &nft_contract, &reputation_contract, user, &metadata)
+        else {
+            return false;
+        };
+
+        set_user_nft_token_id(e, user, token_id);
+
+        NFTMintedEventData {
+            user: user.clone(),
+            token_id,
+            level: base_level.id,
+        }
+        .publish(e);
+
+        UserNFTRegisteredData {
+            user: user.clone(),
+            token_id,
+        }
+        .publish(e);
+
+        true
+    }
+
+    /// Try to upgrade the user's NFT with the new level and points, switching
+    /// to the level's badge template when one is configured.
+    /// Gracefully handles cases where NFT is not configured or user has no NFT.
+    ///
+    /// Returns false if the NFT contract rejected the update.
+    fn try_upgrade_nft_internal(
+        e: &Env,
+        user: &Address,
+        new_level: &LevelDefinition,
+        total_points: u32,
+    ) -> bool {
+        // Check if NFT contract is configured
         let nft_contract = match get_nft_contract(e) {
             Some(addr) => addr,
-            None => return, // No NFT contract configured, skip silently
+            None => return true, // No NFT contract configured, skip silently
         };
 
         // Check if user has an NFT registered
         let token_id = match get_user_nft_token_id(e, user) {
             Some(id) => id,
-            None => return, // User has no NFT, skip silently
+            None => return true, // User has no NFT, skip silently
         };
 
         // Get this contract's address to use as the caller for the NFT update
         // The reputation contract needs the metadata_manager role on the NFT contract
         let reputation_contract = e.current_contract_address();
 
-        // Try to upgrade the NFT metadata with the new level and points
-        let success = nft_client::try_upgrade_nft(
-            e,
-            &nft_contract,
-            &reputation_contract,
-            token_id,
-            new_level,
-            total_points,
-        );
+        // Switch the badge artwork if a template is configured for the new level
+        let mut success = match get_level_template(e, new_level.id) {
+            Some(template) => nft_client::try_apply_level_template(
+                e,
+                &nft_contract,
+                user,
+                token_id,
+                &template,
+                new_level,
+                total_points,
+            ),
+            None => false,
+        };
+
+        // Otherwise (or if that failed, e.g. frozen fields) update the level and points only
+        if !success {
+            success = nft_client::try_upgrade_nft(
+                e,
+                &nft_contract,
+                &reputation_contract,
+                user,
+                token_id,
+                new_level,
+                total_points,
+            );
+        }
 
         // Emit event if upgrade was successful
         if success {
             NFTUpgradedEventData {
                 user: user.clone(),
                 token_id,
-                new_level,
+                new_level: new_level.id,
             }
             .publish(e);
         }
+
+        Self::track_nft_sync_internal(e, user, token_id, new_level, total_points, success);
+        success
+    }
+
+    /// Queue the user for `sync_nft` after a failed NFT update, or clear a
+    /// queued sync after a successful one.
+    fn track_nft_sync_internal(
+        e: &Env,
+        user: &Address,
+        token_id: u32,
+        level: &LevelDefinition,
+        total_points: u32,
+        success: bool,
+    ) {
+        if success {
+            remove_pending_sync(e, user);
+            return;
+        }
+        add_pending_sync(e, user);
+
+        NFTSyncFailedEventData {
+            user: user.clone(),
+            token_id,
+            level: level.id,
+            total_points,
+        }
+        .publish(e);
     }
 
     // ========================================================================
@@ -523,6 +2647,11 @@ impl Reputation {
         Symbol::new(e, CONFIG_ROLE)
     }
 
+    /// Get the moderator role symbol.
+    pub fn moderator_role(e: &Env) -> Symbol {
+        Symbol::new(e, MODERATOR_ROLE)
+    }
+
     /// Extend the TTL of instance storage.
     fn extend_instance_ttl(e: &Env) {
         e.storage()
@@ -0,0 +1,139 @@
+//! Rate limits and recorder budgets.
+//!
+//! Usage is counted in fixed windows: a timestamp falls in window
+//! `timestamp / period_seconds`, and a stored usage from an older window
+//! counts as empty. Limits of 0 mean "unlimited".
+use crate::types::{LimitUsage, RateAllowance, RateLimit};
+
+/// Check that a rate limit is usable.
+pub fn is_valid_limit(limit: &RateLimit) -> bool {
+    limit.period_seconds > 0
+}
+
+/// Get the usage of the window containing `now`.
+pub fn current_usage(usage: LimitUsage, period_seconds: u64, now: u64) -> LimitUsage {
+    let window = now / period_seconds;
+    if usage.window == window {
+        usage
+    } else {
+        LimitUsage {
+            window,
+            ..Default::default()
+        }
+    }
+}
+
+/// Count one more event worth `points` in a usage.
+pub fn add_usage(usage: LimitUsage, points: u32) -> LimitUsage {
+    LimitUsage {
+        window: usage.window,
+        events: usage.events.saturating_add(1),
+        points: usage.points.saturating_add(points),
+    }
+}
+
+/// Combine the usages of two accounts being merged. Only usage of the later
+/// window counts, as an older window has already ended.
+pub fn combine(a: LimitUsage, b: LimitUsage) -> LimitUsage {
+    if a.window != b.window {
+        return if a.window > b.window { a } else { b };
+    }
+    LimitUsage {
+        window: a.window,
+        events: a.events.saturating_add(b.events),
+        points: a.points.saturating_add(b.points),
+    }
+}
+
+/// Check whether one more event worth `points` fits in a rate limit.
+pub fn allows(limit: &RateLimit, usage: &LimitUsage, points: u32) -> bool {
+    fits(limit.max_events, usage.events, 1) && fits(limit.max_points, usage.points, points)
+}
+
+/// Check whether `points` more fit in a budget (0 is not unlimited here: a
+/// budget of 0 stops the recorder from awarding points).
+pub fn within_budget(budget: u32, usage: &LimitUsage, points: u32) -> bool {
+    usage
+        .points
+        .checked_add(points)
+        .is_some_and(|total| total <= budget)
+}
+
+/// Compute what is left of a rate limit in the current window.
+pub fn remaining(limit: &RateLimit, usage: &LimitUsage) -> RateAllowance {
+    let left = |max: u32, used: u32| {
+        if max == 0 {
+            None
+        } else {
+            Some(max.saturating_sub(used))
+        }
+    };
+    RateAllowance {
+        events_remaining: left(limit.max_events, usage.events),
+        points_remaining: left(limit.max_points, usage.points),
+        resets_at: usage
+            .window
+            .saturating_add(1)
+            .saturating_mul(limit.period_seconds),
+    }
+}
+
+fn fits(max: u32, used: u32, amount: u32) -> bool {
+    max == 0 || used.checked_add(amount).is_some_and(|total| total <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(max_events: u32, max_points: u32) -> RateLimit {
+        RateLimit {
+            period_seconds: 100,
+            max_events,
+            max_points,
+        }
+    }
+
+    fn usage(window: u64, events: u32, points: u32) -> LimitUsage {
+        LimitUsage {
+            window,
+            events,
+            points,
+        }
+    }
+
+    #[test]
+    fn test_usage_resets_in_new_window() {
+        assert_eq!(current_usage(usage(1, 3, 30), 100, 199), usage(1, 3, 30));
+        assert_eq!(current_usage(usage(1, 3, 30), 100, 200), usage(2, 0, 0));
+    }
+
+    #[test]
+    fn test_combine() {
+        assert_eq!(combine(usage(2, 1, 10), usage(2, 2, 5)), usage(2, 3, 15));
+        assert_eq!(combine(usage(1, 9, 90), usage(2, 2, 5)), usage(2, 2, 5));
+    }
+
+    #[test]
+    fn test_allows() {
+        assert!(allows(&limit(3, 0), &usage(0, 2, 1000), 1000));
+        assert!(!allows(&limit(3, 0), &usage(0, 3, 0), 1));
+        assert!(allows(&limit(0, 50), &usage(0, 9, 40), 10));
+        assert!(!allows(&limit(0, 50), &usage(0, 0, 40), 11));
+    }
+
+    #[test]
+    fn test_within_budget() {
+        assert!(within_budget(100, &usage(0, 0, 90), 10));
+        assert!(!within_budget(100, &usage(0, 0, 90), 11));
+        assert!(!within_budget(0, &usage(0, 0, 0), 1));
+    }
+
+    #[test]
+    fn test_remaining() {
+        let allowance = remaining(&limit(5, 0), &usage(3, 2, 20));
+        assert_eq!(allowance.events_remaining, Some(3));
+        assert_eq!(allowance.points_remaining, None);
+        assert_eq!(allowance.resets_at, 400);
+    }
+}
@@ -1,17 +1,22 @@
 //! NFT Client module for cross-contract calls to the KindFi NFT contract.
 //!
 //! This module handles the integration between the Reputation contract and the NFT contract,
-//! allowing automatic NFT metadata updates when users level up.
+//! allowing automatic badge minting and NFT metadata updates when users level up.
+//! When a template is configured for the new level, the badge's name, description
+//! and image are switched to it as well.
 //!
 //! ## Setup Required
 //!
 //! For the cross-contract calls to work:
-//! 1. The Reputation contract needs `metadata_manager` role on the NFT contract
+//! 1. The Reputation contract needs `metadata_manager` role on the NFT contract,
+//!    and the `minter` role for automatic badge minting
 //! 2. Register user NFT token IDs using `register_user_nft`
+//! 3. Set this contract as the NFT contract's transfer hook so mappings follow
+//!    transfers and burns (`on_nft_transfer`)
 use itoa;
 use soroban_sdk::{contracttype, Address, ConversionError, Env, IntoVal, InvokeError, String, Symbol, Vec};
 
-use crate::types::Level;
+use crate::types::{LevelDefinition, LevelTemplate};
 
 // ============================================================================
 // NFT Metadata Types (mirrors nft-kindfi types, SEP-0050 compliant)
@@ -28,8 +33,8 @@ pub struct NFTAttribute {
     pub max_value: Option<String>,
 }
 
-/// NFT Metadata structure matching the nft-kindfi contract.
-/// Used for cross-contract calls to update NFT metadata.
+/// NFT metadata following SEP-0050.
+/// Must match the nft-kindfi NFTMetadata structure exactly.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct NFTMetadata {
@@ -50,29 +55,12 @@ const LEVEL_TRAIT_TYPE: &str = "level";
 /// The trait type used for points attributes
 const POINTS_TRAIT_TYPE: &str = "points";
 
-/// Level value constants
-const LEVEL_VALUE_ROOKIE: &str = "rookie";
-const LEVEL_VALUE_BRONZE: &str = "bronze";
-const LEVEL_VALUE_SILVER: &str = "silver";
-const LEVEL_VALUE_GOLD: &str = "gold";
-const LEVEL_VALUE_DIAMOND: &str = "diamond";
-
-/// Get the string value for a level
-fn level_to_value(level: Level) -> &'static str {
-    match level {
-        Level::Rookie => LEVEL_VALUE_ROOKIE,
-        Level::Bronze => LEVEL_VALUE_BRONZE,
-        Level::Silver => LEVEL_VALUE_SILVER,
-        Level::Gold => LEVEL_VALUE_GOLD,
-        Level::Diamond => LEVEL_VALUE_DIAMOND,
-    }
-}
-
-/// Build level attribute from Level enum (SEP-0050 compliant)
-pub fn build_level_attribute(e: &Env, level: Level) -> NFTAttribute {
+/// Build level attribute from a level table entry (SEP-0050 compliant).
+/// The attribute value is the level's name.
+pub fn build_level_attribute(e: &Env, level: &LevelDefinition) -> NFTAttribute {
     NFTAttribute {
         trait_type: String::from_str(e, LEVEL_TRAIT_TYPE),
-        value: String::from_str(e, level_to_value(level)),
+        value: level.name.clone(),
         display_type: Some(String::from_str(e, "string")),
         max_value: None,
     }
@@ -83,6 +71,11 @@ fn is_level_attribute(e: &Env, attr: &NFTAttribute) -> bool {
     attr.trait_type == String::from_str(e, LEVEL_TRAIT_TYPE)
 }
 
+/// Check if an NFTAttribute is a points attribute.
+fn is_points_attribute(e: &Env, attr: &NFTAttribute) -> bool {
+    attr.trait_type == String::from_str(e, POINTS_TRAIT_TYPE)
+}
+
 
 pub fn u32_to_string(env: &Env, value: u32) -> String {
     let mut buffer = itoa::Buffer::new();
@@ -90,11 +83,6 @@ pub fn u32_to_string(env: &Env, value: u32) -> String {
     String::from_str(env, formatted)
 }
 
-/// Check if an NFTAttribute is a points attribute.
-fn is_points_attribute(e: &Env, attr: &NFTAttribute) -> bool {
-    attr.trait_type == String::from_str(e, POINTS_TRAIT_TYPE)
-}
-
 /// Build points attribute from u32 value (SEP-0050 compliant)
 pub fn build_points_attribute(e: &Env, points: u32) -> NFTAttribute {
     // Aquí usamos nuestra nueva función itoa para que el valor sea EXACTO
@@ -108,50 +96,64 @@ pub fn build_points_attribute(e: &Env, points: u32) -> NFTAttribute {
     }
 }
 
-/// Create updated attributes vector with new level and points.
-/// Removes any existing level and points attributes and adds the new ones.
-pub fn update_attributes_with_level_and_points(
+/// Build badge metadata from a level template, with level and points attributes.
+pub fn build_level_metadata(
+    e: &Env,
+    template: &LevelTemplate,
+    level: &LevelDefinition,
+    points: u32,
+) -> NFTMetadata {
+    let mut attributes: Vec<NFTAttribute> = Vec::new(e);
+    attributes.push_back(build_level_attribute(e, level));
+    attributes.push_back(build_points_attribute(e, points));
+
+    NFTMetadata {
+        name: template.name.clone(),
+        description: template.description.clone(),
+        image_uri: template.image_uri.clone(),
+        external_url: String::from_str(e, ""),
+        attributes,
+    }
+}
+
+/// Create updated attributes vector with new level.
+/// Removes any existing level attribute and adds the new one.
+pub fn update_attributes_with_level(
     e: &Env,
     current_attributes: &Vec<NFTAttribute>,
-    new_level: Level,
-    total_points: u32,
+    new_level: &LevelDefinition,
 ) -> Vec<NFTAttribute> {
     let mut new_attrs: Vec<NFTAttribute> = Vec::new(e);
 
-    // Copy non-level and non-points attributes
+    // Copy non-level attributes
     for attr in current_attributes.iter() {
-        if !is_level_attribute(e, &attr) && !is_points_attribute(e, &attr) {
+        if !is_level_attribute(e, &attr) {
             new_attrs.push_back(attr);
         }
     }
 
     // Add new level attribute
     new_attrs.push_back(build_level_attribute(e, new_level));
-    
-    // Add new points attribute
-    new_attrs.push_back(build_points_attribute(e, total_points));
 
     new_attrs
 }
 
-/// Create updated attributes vector with new level.
-/// Removes any existing level attribute and adds the new one.
-pub fn update_attributes_with_level(
+/// Create updated attributes vector with new level and points.
+/// Removes any existing level and points attributes and adds the new ones.
+pub fn update_attributes_with_level_and_points(
     e: &Env,
     current_attributes: &Vec<NFTAttribute>,
-    new_level: Level,
+    new_level: &LevelDefinition,
+    points: u32,
 ) -> Vec<NFTAttribute> {
     let mut new_attrs: Vec<NFTAttribute> = Vec::new(e);
 
-    // Copy non-level attributes
-    for attr in current_attributes.iter() {
-        if !is_level_attribute(e, &attr) {
+    for attr in update_attributes_with_level(e, current_attributes, new_level).iter() {
+        if !is_points_attribute(e, &attr) {
             new_attrs.push_back(attr);
         }
     }
-
-    // Add new level attribute
-    new_attrs.push_back(build_level_attribute(e, new_level));
+    new_attrs.push_back(build_points_attribute(e, points));
 
     new_attrs
 }
@@ -160,42 +162,75 @@ pub fn update_attributes_with_level(
 // Cross-Contract Call Functions
 // ============================================================================
 
-/// Try to get NFT metadata from the NFT contract.
+/// Try to mint a badge via the NFT contract's `mint_with_metadata`.
 ///
 /// # Arguments
 /// * `e` - The environment
 /// * `nft_contract` - Address of the NFT contract
-/// * `token_id` - The NFT token ID to query
+/// * `caller` - Address calling the mint (needs minter role)
+/// * `to` - Recipient of the badge
+/// * `metadata` - Badge metadata
 ///
 /// # Returns
-/// * `Some(NFTMetadata)` if successful
-/// * `None` if the call failed or token not found
-fn try_get_nft_metadata(e: &Env, nft_contract: &Address, token_id: u32) -> Option<NFTMetadata> {
-    let result: Result<Result<Option<NFTMetadata>, ConversionError>, Result<InvokeError, InvokeError>> =
+/// * `Some(token_id)` if the mint was successful
+/// * `None` if the call failed
+pub fn try_mint_nft(
+    e: &Env,
+    nft_contract: &Address,
+    caller: &Address,
+    to: &Address,
+    metadata: &NFTMetadata,
+) -> Option<u32> {
+    let result: Result<Result<u32, ConversionError>, Result<InvokeError, InvokeError>> =
         e.try_invoke_contract(
             nft_contract,
-            &Symbol::new(e, "get_metadata"),
-            (token_id,).into_val(e),
+            &Symbol::new(e, "mint_with_metadata"),
+            (caller.clone(), to.clone(), metadata.clone()).into_val(e),
         );
 
     match result {
-        Ok(Ok(metadata_opt)) => metadata_opt,
+        Ok(Ok(token_id)) => Some(token_id),
         _ => None,
     }
 }
 
-/// Try to update NFT metadata on the NFT contract.
-///
-/// # Arguments
-/// * `e` - The environment
-/// * `nft_contract` - Address of the NFT contract
-/// * `caller` - Address calling the update (needs metadata_manager role)
-/// * `token_id` - The NFT token ID to update
-/// * `metadata` - The new metadata
+/// Try to get the current owner of an NFT via the NFT contract's `owner_of`.
 ///
 /// # Returns
-/// * `true` if the update was successful
-/// * `false` if the call failed
+/// * `Some(owner)` if the token exists
+/// * `None` if the call failed (token burned, contract unreachable, etc.)
+fn try_get_nft_owner(e: &Env, nft_contract: &Address, token_id: u32) -> Option<Address> {
+    let result: Result<Result<Address, ConversionError>, Result<InvokeError, InvokeError>> =
+        e.try_invoke_contract(
+            nft_contract,
+            &Symbol::new(e, "owner_of"),
+            (token_id,).into_val(e),
+        );
+
+    match result {
+        Ok(Ok(owner)) => Some(owner),
+        _ => None,
+    }
+}
+
+/// Try to get an NFT's metadata via the NFT contract's `get_metadata`.
+fn try_get_nft_metadata(e: &Env, nft_contract: &Address, token_id: u32) -> Option<NFTMetadata> {
+    let result: Result<
+        Result<Option<NFTMetadata>, ConversionError>,
+        Result<InvokeError, InvokeError>,
+    > = e.try_invoke_contract(
+        nft_contract,
+        &Symbol::new(e, "get_metadata"),
+        (token_id,).into_val(e),
+    );
+
+    match result {
+        Ok(Ok(metadata)) => metadata,
+        _ => None,
+    }
+}
+
+/// Try to replace an NFT's metadata via the NFT contract's `update_metadata`.
 fn try_update_nft_metadata(
     e: &Env,
     nft_contract: &Address,
@@ -207,55 +242,115 @@ fn try_update_nft_metadata(
         e.try_invoke_contract(
             nft_contract,
             &Symbol::new(e, "update_metadata"),
-            (caller, token_id, metadata).into_val(e),
+            (caller.clone(), token_id, metadata.clone()).into_val(e),
+        );
+
+    matches!(result, Ok(Ok(())))
+}
+
+/// Try to set several attributes of an NFT in one call via the NFT
+/// contract's `set_attributes`.
+fn try_set_nft_attributes(
+    e: &Env,
+    nft_contract: &Address,
+    caller: &Address,
+    token_id: u32,
+    attributes: &Vec<NFTAttribute>,
+) -> bool {
+    let result: Result<Result<(), ConversionError>, Result<InvokeError, InvokeError>> =
+        e.try_invoke_contract(
+            nft_contract,
+            &Symbol::new(e, "set_attributes"),
+            (caller.clone(), token_id, attributes.clone()).into_val(e),
         );
 
     matches!(result, Ok(Ok(())))
 }
 
+/// Try to switch a user's NFT to the template of a new level.
+/// Replaces the name, description and image with the template's, and the
+/// level and points attributes with the new values. Other attributes and the
+/// external URL are kept. This contract is the caller of the update.
+///
+/// # Returns
+/// * `true` if the update was successful
+/// * `false` if the update failed (NFT not found, not owned by `user`, frozen
+///   fields, no permission, etc.)
+pub fn try_apply_level_template(
+    e: &Env,
+    nft_contract: &Address,
+    user: &Address,
+    token_id: u32,
+    template: &LevelTemplate,
+    new_level: &LevelDefinition,
+    total_points: u32,
+) -> bool {
+    if try_get_nft_owner(e, nft_contract, token_id).as_ref() != Some(user) {
+        return false;
+    }
+    let Some(current) = try_get_nft_metadata(e, nft_contract, token_id) else {
+        return false;
+    };
+
+    let metadata = NFTMetadata {
+        name: template.name.clone(),
+        description: template.description.clone(),
+        image_uri: template.image_uri.clone(),
+        external_url: current.external_url,
+        attributes: update_attributes_with_level_and_points(
+            e,
+            &current.attributes,
+            new_level,
+            total_points,
+        ),
+    };
+
+    // This contract needs metadata_manager role on NFT contract
+    let reputation_contract = e.current_contract_address();
+    try_update_nft_metadata(e, nft_contract, &reputation_contract, token_id, &metadata)
+}
+
 /// Try to upgrade user's NFT metadata with new level and points.
-/// This function makes a cross-contract call to the NFT contract.
+/// This function makes cross-contract calls to the NFT contract, setting the
+/// level and points attributes in a single `set_attributes` call so the rest
+/// of the metadata is never read or rewritten.
 ///
 /// # Arguments
 /// * `e` - The environment
 /// * `nft_contract` - Address of the NFT contract
 /// * `reputation_contract` - Address of this contract (caller for metadata update)
+/// * `user` - User the token is registered to; must still own it
 /// * `token_id` - The NFT token ID to update
 /// * `new_level` - The new level to set in the metadata
 /// * `total_points` - The total reputation points to set in the metadata
 ///
 /// # Returns
 /// * `true` if the upgrade was successful
-/// * `false` if the upgrade failed (NFT not found, no permission, etc.)
+/// * `false` if the upgrade failed (NFT not found, not owned by `user`, no permission, etc.)
 pub fn try_upgrade_nft(
     e: &Env,
     nft_contract: &Address,
     reputation_contract: &Address,
+    user: &Address,
     token_id: u32,
-    new_level: Level,
+    new_level: &LevelDefinition,
     total_points: u32,
 ) -> bool {
-    // Try to get current metadata
-    let current_metadata = match try_get_nft_metadata(e, nft_contract, token_id) {
-        Some(meta) => meta,
-        None => return false,
-    };
+    // Never write one user's reputation onto a token someone else owns
+    if try_get_nft_owner(e, nft_contract, token_id).as_ref() != Some(user) {
+        return false;
+    }
 
-    // Update attributes with new level and points
-    let updated_attributes =
-        update_attributes_with_level_and_points(e, &current_metadata.attributes, new_level, total_points);
-
-    // Create updated metadata
-    let updated_metadata = NFTMetadata {
-        name: current_metadata.name,
-        description: current_metadata.description,
-        image_uri: current_metadata.image_uri,
-        external_url: current_metadata.external_url,
-        attributes: updated_attributes,
-    };
+    let attributes = Vec::from_array(
+        e,
+        [
+            build_level_attribute(e, new_level),
+            build_points_attribute(e, total_points),
+        ],
+    );
 
-    // Try to update metadata (this contract needs metadata_manager role on NFT contract)
-    try_update_nft_metadata(e, nft_contract, reputation_contract, token_id, &updated_metadata)
+    // This contract needs metadata_manager role on NFT contract
+    try_set_nft_attributes(e, nft_contract, reputation_contract, token_id, &attributes)
 }
 
 #[cfg(test)]
@@ -266,25 +361,25 @@ mod tests {
     use soroban_sdk::Env;
     use std::string::ToString;
 
+    fn level(env: &Env, name: &str, threshold: u32) -> LevelDefinition {
+        LevelDefinition {
+            id: 0,
+            name: String::from_str(env, name),
+            threshold,
+        }
+    }
+
     #[test]
     fn test_build_level_attribute() {
         let env = Env::default();
 
-        let rookie_attr = build_level_attribute(&env, Level::Rookie);
+        let rookie_attr = build_level_attribute(&env, &level(&env, "rookie", 0));
         assert_eq!(rookie_attr.trait_type.to_string(), "level");
         assert_eq!(rookie_attr.value.to_string(), "rookie");
 
-        let bronze_attr = build_level_attribute(&env, Level::Bronze);
-        assert_eq!(bronze_attr.value.to_string(), "bronze");
-
-        let silver_attr = build_level_attribute(&env, Level::Silver);
-        assert_eq!(silver_attr.value.to_string(), "silver");
-
-        let gold_attr = build_level_attribute(&env, Level::Gold);
-        assert_eq!(gold_attr.value.to_string(), "gold");
-
-        let diamond_attr = build_level_attribute(&env, Level::Diamond);
-        assert_eq!(diamond_attr.value.to_string(), "diamond");
+        // The value follows the configured level name
+        let custom_attr = build_level_attribute(&env, &level(&env, "platinum", 10000));
+        assert_eq!(custom_attr.value.to_string(), "platinum");
     }
 
     #[test]
@@ -307,7 +402,7 @@ mod tests {
         });
 
         // Update to Bronze level
-        let new_attrs = update_attributes_with_level(&env, &attrs, Level::Bronze);
+        let new_attrs = update_attributes_with_level(&env, &attrs, &level(&env, "bronze", 200));
 
         // Should have 3 attributes: badge, tier, and new level
         assert_eq!(new_attrs.len(), 3);
@@ -321,7 +416,7 @@ mod tests {
         let attrs: Vec<NFTAttribute> = Vec::new(&env);
 
         // Update to Diamond level
-        let new_attrs = update_attributes_with_level(&env, &attrs, Level::Diamond);
+        let new_attrs = update_attributes_with_level(&env, &attrs, &level(&env, "diamond", 5000));
 
         // Should have 1 attribute: the new level
         assert_eq!(new_attrs.len(), 1);
@@ -358,7 +453,7 @@ mod tests {
         });
 
         // Update to Gold level
-        let new_attrs = update_attributes_with_level(&env, &attrs, Level::Gold);
+        let new_attrs = update_attributes_with_level(&env, &attrs, &level(&env, "gold", 1000));
 
         // Should still have 3 attributes: badge, tier, and new level (rookie replaced)
         assert_eq!(new_attrs.len(), 3);
@@ -382,6 +477,30 @@ mod tests {
     }
 
      
+    #[test]
+    fn test_update_attributes_replaces_level_and_points() {
+        let env = Env::default();
+
+        let mut attrs: Vec<NFTAttribute> = Vec::new(&env);
+        attrs.push_back(build_level_attribute(&env, &level(&env, "rookie", 0)));
+        attrs.push_back(build_points_attribute(&env, 150));
+        attrs.push_back(NFTAttribute {
+            trait_type: String::from_str(&env, "badge"),
+            value: String::from_str(&env, "early_supporter"),
+            display_type: None,
+            max_value: None,
+        });
+
+        let bronze = level(&env, "bronze", 200);
+        let new_attrs = update_attributes_with_level_and_points(&env, &attrs, &bronze, 250);
+
+        // badge, level and points: nothing duplicated
+        assert_eq!(new_attrs.len(), 3);
+        assert!(new_attrs.contains(build_level_attribute(&env, &bronze)));
+        assert!(new_attrs.contains(build_points_attribute(&env, 250)));
+        assert!(!new_attrs.contains(build_points_attribute(&env, 150)));
+    }
+
     #[test]
     fn test_u32_to_string_integrity() {
         let env = Env::default();
@@ -1,10 +1,11 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Vec};
 
+use crate::checkpoints;
 use crate::types::{
-    EventType, Level, ReputationEventRecord, StorageKey, ThresholdType,
-    BRONZE_THRESHOLD, DEFAULT_BOOSTED_PROJECT_POINTS, DEFAULT_DONATION_POINTS,
-    DEFAULT_NEW_CAMPAIGN_POINTS, DEFAULT_NEW_CATEGORY_POINTS, DEFAULT_OUTSTANDING_BOOSTER_POINTS,
-    DEFAULT_QUEST_COMPLETION_POINTS, DEFAULT_REFERRAL_POINTS, DEFAULT_STREAK_DONATION_POINTS,
+    BonusCampaign, Checkpoint, DecayPolicy, EventHistoryIndex, EventType, EventTypeConfig,
+    EventTypeSummary, ExternalRefRecord, LeaderboardEntry, Level, LevelDefinition, LevelTemplate,
+    LimitMode, LimitUsage, MergeProposal, RateLimit, ReputationEventRecord, Season, StorageKey,
+    ThresholdType, BRONZE_THRESHOLD, DEFAULT_HISTORY_RETENTION, DEFAULT_LEADERBOARD_SIZE,
     DIAMOND_THRESHOLD, GOLD_THRESHOLD, ROOKIE_THRESHOLD, SILVER_THRESHOLD,
 };
 
@@ -13,6 +14,21 @@ const DAY_IN_LEDGERS: u32 = 17280;
 const PERSISTENT_TTL_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const PERSISTENT_TTL_THRESHOLD: u32 = PERSISTENT_TTL_AMOUNT - DAY_IN_LEDGERS;
 
+/// Maximum number of history records returned per page
+pub const MAX_HISTORY_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of leaderboard entries returned per page
+pub const MAX_LEADERBOARD_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of pending NFT syncs returned per page
+pub const MAX_PENDING_SYNC_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of history records rolled up by a single write
+const MAX_ROLLUPS_PER_WRITE: u32 = 5;
+
+/// External references are kept long enough to absorb backend retries (7 days)
+const EXTERNAL_REF_TTL_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+
 // ============================================================================
 // Points Storage
 // ============================================================================
@@ -42,11 +58,11 @@ pub fn set_points(e: &Env, user: &Address, points: u32) {
 // Level Storage
 // ============================================================================
 
-/// Get user's current level
-pub fn get_user_level(e: &Env, user: &Address) -> Level {
+/// Get user's current level id (None until the user first changes level)
+pub fn get_user_level(e: &Env, user: &Address) -> Option<u32> {
     let key = StorageKey::UserLevel(user.clone());
-    let level = e.storage().persistent().get(&key).unwrap_or(Level::Rookie);
-    if level != Level::Rookie {
+    let level: Option<u32> = e.storage().persistent().get(&key);
+    if level.is_some() {
         e.storage()
             .persistent()
             .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
@@ -54,8 +70,8 @@ pub fn get_user_level(e: &Env, user: &Address) -> Level {
     level
 }
 
-/// Set user's current level
-pub fn set_user_level(e: &Env, user: &Address, level: Level) {
+/// Set user's current level id
+pub fn set_user_level(e: &Env, user: &Address, level: u32) {
     let key = StorageKey::UserLevel(user.clone());
     e.storage().persistent().set(&key, &level);
     e.storage()
@@ -67,33 +83,535 @@ pub fn set_user_level(e: &Env, user: &Address, level: Level) {
 // Events History Storage
 // ============================================================================
 
-/// Get user's event history
-pub fn get_user_events(e: &Env, user: &Address) -> Vec<ReputationEventRecord> {
-    let key = StorageKey::UserEvents(user.clone());
-    let events: Vec<ReputationEventRecord> = e
-        .storage()
+/// Get the position of user's event history
+pub fn get_event_history_index(e: &Env, user: &Address) -> EventHistoryIndex {
+    let key = StorageKey::UserEventIndex(user.clone());
+    let index: Option<EventHistoryIndex> = e.storage().persistent().get(&key);
+    match index {
+        Some(index) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+            index
+        }
+        None => EventHistoryIndex::default(),
+    }
+}
+
+fn set_event_history_index(e: &Env, user: &Address, index: &EventHistoryIndex) {
+    let key = StorageKey::UserEventIndex(user.clone());
+    e.storage().persistent().set(&key, index);
+    e.storage()
         .persistent()
-        .get(&key)
-        .unwrap_or(Vec::new(e));
-    if !events.is_empty() {
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Get the total number of events recorded for a user, including rolled-up ones
+pub fn get_user_event_count(e: &Env, user: &Address) -> u32 {
+    get_event_history_index(e, user).next
+}
+
+/// Get a single history record by its index
+pub fn get_user_event(e: &Env, user: &Address, index: u32) -> Option<ReputationEventRecord> {
+    let key = StorageKey::UserEvent(user.clone(), index);
+    let event: Option<ReputationEventRecord> = e.storage().persistent().get(&key);
+    if event.is_some() {
         e.storage()
             .persistent()
             .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
     }
+    event
+}
+
+/// Get a page of user's retained event history, oldest first.
+///
+/// `offset` is the index of the event among all events ever recorded for the
+/// user, so pages stay stable as old records are rolled up. Rolled-up
+/// indexes are skipped.
+pub fn get_user_events(e: &Env, user: &Address, offset: u32, limit: u32) -> Vec<ReputationEventRecord> {
+    let index = get_event_history_index(e, user);
+    let start = offset.max(index.first);
+    let end = offset
+        .saturating_add(limit.min(MAX_HISTORY_PAGE_SIZE))
+        .min(index.next);
+
+    let mut events = Vec::new(e);
+    for i in start..end {
+        if let Some(event) = get_user_event(e, user, i) {
+            events.push_back(event);
+        }
+    }
     events
 }
 
-/// Add an event to user's history
+/// Get the summary of user's rolled-up event history
+pub fn get_user_event_summary(e: &Env, user: &Address) -> Map<u32, EventTypeSummary> {
+    let key = StorageKey::UserEventSummary(user.clone());
+    let summary: Option<Map<u32, EventTypeSummary>> = e.storage().persistent().get(&key);
+    match summary {
+        Some(summary) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+            summary
+        }
+        None => Map::new(e),
+    }
+}
+
+/// Add an event to user's history, rolling up records beyond the retention cap
 pub fn add_user_event(e: &Env, user: &Address, event: ReputationEventRecord) {
-    let key = StorageKey::UserEvents(user.clone());
-    let mut events = get_user_events(e, user);
-    events.push_back(event);
-    e.storage().persistent().set(&key, &events);
+    let mut index = get_event_history_index(e, user);
+
+    let key = StorageKey::UserEvent(user.clone(), index.next);
+    e.storage().persistent().set(&key, &event);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    index.next += 1;
+
+    // Roll up a bounded number of records per write so a lowered cap
+    // converges without one write paying for the whole backlog
+    let retention = get_history_retention(e);
+    let excess = (index.next - index.first).saturating_sub(retention);
+    let rollups = excess.min(MAX_ROLLUPS_PER_WRITE);
+    if rollups > 0 {
+        let mut summary = get_user_event_summary(e, user);
+        for _ in 0..rollups {
+            let key = StorageKey::UserEvent(user.clone(), index.first);
+            let record: Option<ReputationEventRecord> = e.storage().persistent().get(&key);
+            if let Some(record) = record {
+                roll_up(&mut summary, &record);
+                e.storage().persistent().remove(&key);
+            }
+            index.first += 1;
+        }
+        set_user_event_summary(e, user, &summary);
+    }
+
+    set_event_history_index(e, user, &index);
+}
+
+fn set_user_event_summary(e: &Env, user: &Address, summary: &Map<u32, EventTypeSummary>) {
+    let key = StorageKey::UserEventSummary(user.clone());
+    e.storage().persistent().set(&key, summary);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+fn roll_up(summary: &mut Map<u32, EventTypeSummary>, record: &ReputationEventRecord) {
+    let mut entry = summary.get(record.event_type).unwrap_or_default();
+    entry.event_count = entry.event_count.saturating_add(1);
+    if record.deduction_reason.is_some() {
+        entry.points_deducted = entry.points_deducted.saturating_add(record.points);
+    } else {
+        entry.points_awarded = entry.points_awarded.saturating_add(record.points);
+    }
+    entry.last_timestamp = entry.last_timestamp.max(record.timestamp);
+    summary.set(record.event_type, entry);
+}
+
+fn remove_event_history(e: &Env, user: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&StorageKey::UserEventIndex(user.clone()));
+    e.storage()
+        .persistent()
+        .remove(&StorageKey::UserEventSummary(user.clone()));
+}
+
+/// Move user's event history, with its indexes, to an account without history
+pub fn move_user_events(e: &Env, from: &Address, to: &Address) {
+    let index = get_event_history_index(e, from);
+    for i in index.first..index.next {
+        let key = StorageKey::UserEvent(from.clone(), i);
+        let record: Option<ReputationEventRecord> = e.storage().persistent().get(&key);
+        if let Some(record) = record {
+            let to_key = StorageKey::UserEvent(to.clone(), i);
+            e.storage().persistent().set(&to_key, &record);
+            e.storage()
+                .persistent()
+                .extend_ttl(&to_key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+            e.storage().persistent().remove(&key);
+        }
+    }
+    set_event_history_index(e, to, &index);
+
+    let summary = get_user_event_summary(e, from);
+    if !summary.is_empty() {
+        set_user_event_summary(e, to, &summary);
+    }
+    remove_event_history(e, from);
+}
+
+/// Roll user's whole event history, retained records and summary, into
+/// another account's summary
+pub fn fold_user_events(e: &Env, from: &Address, to: &Address) {
+    let mut summary = get_user_event_summary(e, to);
+    for (event_type, entry) in get_user_event_summary(e, from).iter() {
+        let mut merged = summary.get(event_type).unwrap_or_default();
+        merged.event_count = merged.event_count.saturating_add(entry.event_count);
+        merged.points_awarded = merged.points_awarded.saturating_add(entry.points_awarded);
+        merged.points_deducted = merged.points_deducted.saturating_add(entry.points_deducted);
+        merged.last_timestamp = merged.last_timestamp.max(entry.last_timestamp);
+        summary.set(event_type, merged);
+    }
+
+    let index = get_event_history_index(e, from);
+    for i in index.first..index.next {
+        let key = StorageKey::UserEvent(from.clone(), i);
+        let record: Option<ReputationEventRecord> = e.storage().persistent().get(&key);
+        if let Some(record) = record {
+            roll_up(&mut summary, &record);
+            e.storage().persistent().remove(&key);
+        }
+    }
+
+    if !summary.is_empty() {
+        set_user_event_summary(e, to, &summary);
+    }
+    remove_event_history(e, from);
+}
+
+/// Get the timestamp user's decay is counted from
+pub fn get_last_activity(e: &Env, user: &Address) -> Option<u64> {
+    let key = StorageKey::LastActivity(user.clone());
+    let value: Option<u64> = e.storage().persistent().get(&key);
+    if value.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    value
+}
+
+/// Set the timestamp user's decay is counted from
+pub fn set_last_activity(e: &Env, user: &Address, timestamp: u64) {
+    let key = StorageKey::LastActivity(user.clone());
+    e.storage().persistent().set(&key, &timestamp);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Forget the timestamp user's decay is counted from
+pub fn remove_last_activity(e: &Env, user: &Address) {
+    let key = StorageKey::LastActivity(user.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/// Get when an event type was last recorded for a user
+pub fn get_last_event_of_type(e: &Env, user: &Address, event_type: u32) -> Option<u64> {
+    let key = StorageKey::LastEventOfType(user.clone(), event_type);
+    let value: Option<u64> = e.storage().persistent().get(&key);
+    if value.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    value
+}
+
+/// Set when an event type was last recorded for a user
+pub fn set_last_event_of_type(e: &Env, user: &Address, event_type: u32, timestamp: u64) {
+    let key = StorageKey::LastEventOfType(user.clone(), event_type);
+    e.storage().persistent().set(&key, &timestamp);
     e.storage()
         .persistent()
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
 }
 
+/// Forget when an event type was last recorded for a user
+pub fn remove_last_event_of_type(e: &Env, user: &Address, event_type: u32) {
+    let key = StorageKey::LastEventOfType(user.clone(), event_type);
+    e.storage().persistent().remove(&key);
+}
+
+// ============================================================================
+// External Reference Storage
+// ============================================================================
+
+/// Get the outcome recorded for an external reference
+pub fn get_external_ref(e: &Env, external_ref: &BytesN<32>) -> Option<ExternalRefRecord> {
+    let key = StorageKey::ExternalRef(external_ref.clone());
+    e.storage().temporary().get(&key)
+}
+
+/// Record the outcome of an event for an external reference
+pub fn set_external_ref(e: &Env, external_ref: &BytesN<32>, record: &ExternalRefRecord) {
+    let key = StorageKey::ExternalRef(external_ref.clone());
+    e.storage().temporary().set(&key, record);
+    e.storage()
+        .temporary()
+        .extend_ttl(&key, EXTERNAL_REF_TTL_AMOUNT, EXTERNAL_REF_TTL_AMOUNT);
+}
+
+// ============================================================================
+// Rate Limit Usage Storage
+// ============================================================================
+
+/// Get user's stored usage of an event type's rate limit
+pub fn get_rate_limit_usage(e: &Env, user: &Address, event_type: u32) -> LimitUsage {
+    let key = StorageKey::RateLimitUsage(user.clone(), event_type);
+    let usage: Option<LimitUsage> = e.storage().persistent().get(&key);
+    if usage.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    usage.unwrap_or_default()
+}
+
+/// Set user's usage of an event type's rate limit
+pub fn set_rate_limit_usage(e: &Env, user: &Address, event_type: u32, usage: &LimitUsage) {
+    let key = StorageKey::RateLimitUsage(user.clone(), event_type);
+    e.storage().persistent().set(&key, usage);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Remove user's usage of an event type's rate limit
+pub fn remove_rate_limit_usage(e: &Env, user: &Address, event_type: u32) {
+    let key = StorageKey::RateLimitUsage(user.clone(), event_type);
+    e.storage().persistent().remove(&key);
+}
+
+/// Get recorder's stored usage of its daily budget
+pub fn get_recorder_usage(e: &Env, recorder: &Address) -> LimitUsage {
+    let key = StorageKey::RecorderUsage(recorder.clone());
+    let usage: Option<LimitUsage> = e.storage().persistent().get(&key);
+    if usage.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    usage.unwrap_or_default()
+}
+
+/// Set recorder's usage of its daily budget
+pub fn set_recorder_usage(e: &Env, recorder: &Address, usage: &LimitUsage) {
+    let key = StorageKey::RecorderUsage(recorder.clone());
+    e.storage().persistent().set(&key, usage);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+// ============================================================================
+// Checkpoint Storage
+// ============================================================================
+
+/// Get the number of checkpoints of a user
+pub fn get_checkpoint_count(e: &Env, user: &Address) -> u32 {
+    let key = StorageKey::CheckpointCount(user.clone());
+    let count = e.storage().persistent().get(&key).unwrap_or(0);
+    if count > 0 {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    count
+}
+
+/// Get one checkpoint of a user
+pub fn get_checkpoint(e: &Env, user: &Address, index: u32) -> Option<Checkpoint> {
+    let key = StorageKey::Checkpoint(user.clone(), index);
+    let checkpoint: Option<Checkpoint> = e.storage().persistent().get(&key);
+    if checkpoint.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    checkpoint
+}
+
+/// Record user's points and level at the current ledger. A checkpoint from
+/// the same ledger is overwritten, so ledgers stay strictly increasing.
+pub fn add_checkpoint(e: &Env, user: &Address, points: u32, level: u32) {
+    let ledger = e.ledger().sequence();
+    let count = get_checkpoint_count(e, user);
+
+    let same_ledger =
+        count > 0 && get_checkpoint(e, user, count - 1).is_some_and(|last| last.ledger == ledger);
+    let index = if same_ledger { count - 1 } else { count };
+
+    let key = StorageKey::Checkpoint(user.clone(), index);
+    e.storage().persistent().set(
+        &key,
+        &Checkpoint {
+            ledger,
+            points,
+            level,
+        },
+    );
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+
+    if !same_ledger {
+        let count_key = StorageKey::CheckpointCount(user.clone());
+        e.storage().persistent().set(&count_key, &(count + 1));
+        e.storage()
+            .persistent()
+            .extend_ttl(&count_key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+}
+
+/// Get user's last checkpoint at or before a ledger
+pub fn get_checkpoint_at(e: &Env, user: &Address, ledger: u32) -> Option<Checkpoint> {
+    let count = get_checkpoint_count(e, user);
+    let index = checkpoints::find_index(count, ledger, |index| {
+        get_checkpoint(e, user, index).map_or(u32::MAX, |checkpoint| checkpoint.ledger)
+    })?;
+    get_checkpoint(e, user, index)
+}
+
+// ============================================================================
+// Season Storage
+// ============================================================================
+
+/// Get the id of the latest season (0 before the first season)
+pub fn get_current_season_id(e: &Env) -> u32 {
+    let key = StorageKey::CurrentSeason;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Set the id of the latest season
+pub fn set_current_season_id(e: &Env, season_id: u32) {
+    let key = StorageKey::CurrentSeason;
+    e.storage().instance().set(&key, &season_id);
+}
+
+/// Get a season's info
+pub fn get_season(e: &Env, season_id: u32) -> Option<Season> {
+    let key = StorageKey::Season(season_id);
+    let season: Option<Season> = e.storage().persistent().get(&key);
+    if season.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    season
+}
+
+/// Set a season's info
+pub fn set_season(e: &Env, season: &Season) {
+    let key = StorageKey::Season(season.id);
+    e.storage().persistent().set(&key, season);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Get user's points in a season
+pub fn get_season_points(e: &Env, season_id: u32, user: &Address) -> u32 {
+    let key = StorageKey::SeasonPoints(season_id, user.clone());
+    let points = e.storage().persistent().get(&key).unwrap_or(0);
+    if points > 0 {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    points
+}
+
+/// Set user's points in a season
+pub fn set_season_points(e: &Env, season_id: u32, user: &Address, points: u32) {
+    let key = StorageKey::SeasonPoints(season_id, user.clone());
+    e.storage().persistent().set(&key, &points);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Get the level table frozen at a season's end
+pub fn get_season_level_table(e: &Env, season_id: u32) -> Option<Vec<LevelDefinition>> {
+    let key = StorageKey::SeasonLevelTable(season_id);
+    let levels: Option<Vec<LevelDefinition>> = e.storage().persistent().get(&key);
+    if levels.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    levels
+}
+
+/// Freeze the level table of a season
+pub fn set_season_level_table(e: &Env, season_id: u32, levels: &Vec<LevelDefinition>) {
+    let key = StorageKey::SeasonLevelTable(season_id);
+    e.storage().persistent().set(&key, levels);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+// ============================================================================
+// Leaderboard Storage
+// ============================================================================
+
+/// Get the lifetime leaderboard
+pub fn get_leaderboard(e: &Env) -> Vec<LeaderboardEntry> {
+    let key = StorageKey::Leaderboard;
+    let board: Option<Vec<LeaderboardEntry>> = e.storage().persistent().get(&key);
+    match board {
+        Some(board) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+            board
+        }
+        None => Vec::new(e),
+    }
+}
+
+/// Set the lifetime leaderboard
+pub fn set_leaderboard(e: &Env, board: &Vec<LeaderboardEntry>) {
+    let key = StorageKey::Leaderboard;
+    e.storage().persistent().set(&key, board);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Get a season's leaderboard
+pub fn get_season_leaderboard(e: &Env, season_id: u32) -> Vec<LeaderboardEntry> {
+    let key = StorageKey::SeasonLeaderboard(season_id);
+    let board: Option<Vec<LeaderboardEntry>> = e.storage().persistent().get(&key);
+    match board {
+        Some(board) => {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+            board
+        }
+        None => Vec::new(e),
+    }
+}
+
+/// Set a season's leaderboard
+pub fn set_season_leaderboard(e: &Env, season_id: u32, board: &Vec<LeaderboardEntry>) {
+    let key = StorageKey::SeasonLeaderboard(season_id);
+    e.storage().persistent().set(&key, board);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Get a page of a leaderboard, capped at `MAX_LEADERBOARD_PAGE_SIZE`
+pub fn leaderboard_page(
+    board: &Vec<LeaderboardEntry>,
+    offset: u32,
+    limit: u32,
+) -> Vec<LeaderboardEntry> {
+    let start = offset.min(board.len());
+    let end = start
+        .saturating_add(limit.min(MAX_LEADERBOARD_PAGE_SIZE))
+        .min(board.len());
+    board.slice(start..end)
+}
+
 // ============================================================================
 // NFT Token ID Storage
 // ============================================================================
@@ -119,6 +637,115 @@ pub fn set_user_nft_token_id(e: &Env, user: &Address, token_id: u32) {
         .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
 }
 
+/// Remove user's NFT token ID
+pub fn remove_user_nft_token_id(e: &Env, user: &Address) {
+    let key = StorageKey::UserNFTTokenId(user.clone());
+    e.storage().persistent().remove(&key);
+}
+
+// ============================================================================
+// Pending NFT Sync Storage
+// ============================================================================
+
+/// Get the number of users whose NFT is out of sync
+pub fn get_pending_sync_count(e: &Env) -> u32 {
+    let key = StorageKey::PendingSyncCount;
+    let count = e.storage().persistent().get(&key).unwrap_or(0);
+    if count > 0 {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    count
+}
+
+fn set_pending_sync_count(e: &Env, count: u32) {
+    let key = StorageKey::PendingSyncCount;
+    e.storage().persistent().set(&key, &count);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+fn get_pending_sync(e: &Env, slot: u32) -> Option<Address> {
+    let key = StorageKey::PendingSync(slot);
+    let user: Option<Address> = e.storage().persistent().get(&key);
+    if user.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    user
+}
+
+fn set_pending_sync(e: &Env, slot: u32, user: &Address) {
+    let key = StorageKey::PendingSync(slot);
+    e.storage().persistent().set(&key, user);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+
+    let slot_key = StorageKey::PendingSyncSlot(user.clone());
+    e.storage().persistent().set(&slot_key, &slot);
+    e.storage()
+        .persistent()
+        .extend_ttl(&slot_key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Check whether user's NFT is out of sync
+pub fn is_sync_pending(e: &Env, user: &Address) -> bool {
+    let key = StorageKey::PendingSyncSlot(user.clone());
+    e.storage().persistent().has(&key)
+}
+
+/// Add user to the pending NFT syncs, if not already there
+pub fn add_pending_sync(e: &Env, user: &Address) {
+    if is_sync_pending(e, user) {
+        return;
+    }
+    let count = get_pending_sync_count(e);
+    set_pending_sync(e, count, user);
+    set_pending_sync_count(e, count + 1);
+}
+
+/// Remove user from the pending NFT syncs, moving the last entry into its
+/// slot
+pub fn remove_pending_sync(e: &Env, user: &Address) {
+    let slot_key = StorageKey::PendingSyncSlot(user.clone());
+    let Some(slot) = e.storage().persistent().get::<_, u32>(&slot_key) else {
+        return;
+    };
+    e.storage().persistent().remove(&slot_key);
+
+    let last = get_pending_sync_count(e) - 1;
+    if slot != last {
+        if let Some(moved) = get_pending_sync(e, last) {
+            set_pending_sync(e, slot, &moved);
+        }
+    }
+    let last_key = StorageKey::PendingSync(last);
+    e.storage().persistent().remove(&last_key);
+    set_pending_sync_count(e, last);
+}
+
+/// Get a page of the users whose NFT is out of sync. Removals move the last
+/// entry into the freed slot, so the order is not stable.
+pub fn get_pending_syncs(e: &Env, offset: u32, limit: u32) -> Vec<Address> {
+    let count = get_pending_sync_count(e);
+    let start = offset.min(count);
+    let end = start
+        .saturating_add(limit.min(MAX_PENDING_SYNC_PAGE_SIZE))
+        .min(count);
+
+    let mut users = Vec::new(e);
+    for slot in start..end {
+        if let Some(user) = get_pending_sync(e, slot) {
+            users.push_back(user);
+        }
+    }
+    users
+}
+
 // ============================================================================
 // Configuration Storage
 // ============================================================================
@@ -135,51 +762,74 @@ pub fn set_nft_contract(e: &Env, address: &Address) {
     e.storage().instance().set(&key, address);
 }
 
-/// Get level threshold (returns default if not set)
-pub fn get_level_threshold(e: &Env, level: Level) -> u32 {
-    let key = StorageKey::LevelThreshold(level);
-    e.storage().instance().get(&key).unwrap_or_else(|| {
-        match level {
-            Level::Rookie => ROOKIE_THRESHOLD,
-            Level::Bronze => BRONZE_THRESHOLD,
-            Level::Silver => SILVER_THRESHOLD,
-            Level::Gold => GOLD_THRESHOLD,
-            Level::Diamond => DIAMOND_THRESHOLD,
-        }
-    })
+/// Get the level table (returns the default five levels if not set)
+pub fn get_level_table(e: &Env) -> Vec<LevelDefinition> {
+    let key = StorageKey::LevelTable;
+    e.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| default_level_table(e))
 }
 
-/// Set level threshold
-pub fn set_level_threshold(e: &Env, level: Level, threshold: u32) {
-    let key = StorageKey::LevelThreshold(level);
-    e.storage().instance().set(&key, &threshold);
+/// Set the level table
+pub fn set_level_table(e: &Env, levels: &Vec<LevelDefinition>) {
+    let key = StorageKey::LevelTable;
+    e.storage().instance().set(&key, levels);
 }
 
-/// Get event point value (returns default if not set)
-pub fn get_event_point_value(e: &Env, event_type: EventType) -> u32 {
-    let key = StorageKey::EventPointValue(event_type);
-    e.storage().instance().get(&key).unwrap_or_else(|| {
-        match event_type {
-            EventType::Donation => DEFAULT_DONATION_POINTS,
-            EventType::StreakDonation => DEFAULT_STREAK_DONATION_POINTS,
-            EventType::SuccessfulReferral => DEFAULT_REFERRAL_POINTS,
-            EventType::NewCategoryDonation => DEFAULT_NEW_CATEGORY_POINTS,
-            EventType::NewCampaignDonation => DEFAULT_NEW_CAMPAIGN_POINTS,
-            EventType::QuestCompletion => DEFAULT_QUEST_COMPLETION_POINTS,
-            EventType::BoostedProject => DEFAULT_BOOSTED_PROJECT_POINTS,
-            EventType::OutstandingBooster => DEFAULT_OUTSTANDING_BOOSTER_POINTS,
-        }
+fn default_level_table(e: &Env) -> Vec<LevelDefinition> {
+    let defaults = [
+        (Level::Rookie, "rookie", ROOKIE_THRESHOLD),
+        (Level::Bronze, "bronze", BRONZE_THRESHOLD),
+        (Level::Silver, "silver", SILVER_THRESHOLD),
+        (Level::Gold, "gold", GOLD_THRESHOLD),
+        (Level::Diamond, "diamond", DIAMOND_THRESHOLD),
+    ];
+
+    let mut levels = Vec::new(e);
+    for (level, name, threshold) in defaults {
+        levels.push_back(LevelDefinition {
+            id: level.as_u32(),
+            name: String::from_str(e, name),
+            threshold,
+        });
+    }
+    levels
+}
+
+/// Get an event type's registry entry (built-ins have a default entry)
+pub fn get_event_type_config(e: &Env, event_type: u32) -> Option<EventTypeConfig> {
+    let key = StorageKey::EventTypeConfig(event_type);
+    e.storage().instance().get(&key).or_else(|| {
+        EventType::from_u32(event_type).map(|builtin| EventTypeConfig {
+            name: String::from_str(e, builtin.name()),
+            default_points: builtin.default_points(),
+            enabled: true,
+            cooldown_seconds: 0,
+        })
     })
 }
 
-/// Set event point value
-pub fn set_event_point_value(e: &Env, event_type: EventType, points: u32) {
-    let key = StorageKey::EventPointValue(event_type);
-    e.storage().instance().set(&key, &points);
+/// Set an event type's registry entry
+pub fn set_event_type_config(e: &Env, event_type: u32, config: &EventTypeConfig) {
+    let key = StorageKey::EventTypeConfig(event_type);
+    e.storage().instance().set(&key, config);
+}
+
+/// Get the ids of registered custom event types
+pub fn get_custom_event_types(e: &Env) -> Vec<u32> {
+    let key = StorageKey::CustomEventTypes;
+    e.storage().instance().get(&key).unwrap_or(Vec::new(e))
 }
 
-/// Get permission threshold (returns default if not set)
-pub fn get_permission_threshold(e: &Env, threshold_type: ThresholdType) -> Level {
+/// Set the ids of registered custom event types
+pub fn set_custom_event_types(e: &Env, event_types: &Vec<u32>) {
+    let key = StorageKey::CustomEventTypes;
+    e.storage().instance().set(&key, event_types);
+}
+
+/// Get permission threshold level id (returns default if not set)
+pub fn get_permission_threshold(e: &Env, threshold_type: ThresholdType) -> u32 {
     let key = StorageKey::PermissionThreshold(threshold_type);
     e.storage().instance().get(&key).unwrap_or_else(|| {
         match threshold_type {
@@ -188,15 +838,197 @@ pub fn get_permission_threshold(e: &Env, threshold_type: ThresholdType) -> Level
             ThresholdType::ExclusiveRounds => Level::Gold,
             ThresholdType::SpecialRewards => Level::Diamond,
         }
+        .as_u32()
     })
 }
 
-/// Set permission threshold
-pub fn set_permission_threshold(e: &Env, threshold_type: ThresholdType, level: Level) {
+/// Set permission threshold level id
+pub fn set_permission_threshold(e: &Env, threshold_type: ThresholdType, level: u32) {
     let key = StorageKey::PermissionThreshold(threshold_type);
     e.storage().instance().set(&key, &level);
 }
 
+/// Check if badges are minted automatically for users without an NFT
+pub fn is_auto_mint_enabled(e: &Env) -> bool {
+    let key = StorageKey::AutoMintEnabled;
+    e.storage().instance().get(&key).unwrap_or(false)
+}
+
+/// Enable or disable automatic badge minting
+pub fn set_auto_mint_enabled(e: &Env, enabled: bool) {
+    let key = StorageKey::AutoMintEnabled;
+    e.storage().instance().set(&key, &enabled);
+}
+
+/// Get the badge template for a level
+pub fn get_level_template(e: &Env, level: u32) -> Option<LevelTemplate> {
+    let key = StorageKey::LevelTemplate(level);
+    e.storage().instance().get(&key)
+}
+
+/// Set the badge template for a level
+pub fn set_level_template(e: &Env, level: u32, template: &LevelTemplate) {
+    let key = StorageKey::LevelTemplate(level);
+    e.storage().instance().set(&key, template);
+}
+
+/// Get the inactivity decay policy
+pub fn get_decay_policy(e: &Env) -> Option<DecayPolicy> {
+    let key = StorageKey::DecayPolicy;
+    e.storage().instance().get(&key)
+}
+
+/// Set or remove the inactivity decay policy
+pub fn set_decay_policy(e: &Env, policy: &Option<DecayPolicy>) {
+    let key = StorageKey::DecayPolicy;
+    match policy {
+        Some(policy) => e.storage().instance().set(&key, policy),
+        None => e.storage().instance().remove(&key),
+    }
+}
+
+/// Get the number of history records kept per user
+pub fn get_history_retention(e: &Env) -> u32 {
+    let key = StorageKey::HistoryRetention;
+    e.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(DEFAULT_HISTORY_RETENTION)
+}
+
+/// Set the number of history records kept per user
+pub fn set_history_retention(e: &Env, retention: u32) {
+    let key = StorageKey::HistoryRetention;
+    e.storage().instance().set(&key, &retention);
+}
+
+/// Get an event type's per-user rate limit
+pub fn get_rate_limit(e: &Env, event_type: u32) -> Option<RateLimit> {
+    let key = StorageKey::RateLimit(event_type);
+    e.storage().instance().get(&key)
+}
+
+/// Set or remove an event type's per-user rate limit
+pub fn set_rate_limit(e: &Env, event_type: u32, limit: &Option<RateLimit>) {
+    let key = StorageKey::RateLimit(event_type);
+    match limit {
+        Some(limit) => e.storage().instance().set(&key, limit),
+        None => e.storage().instance().remove(&key),
+    }
+}
+
+/// Get the daily points budget of each recorder
+pub fn get_recorder_budget(e: &Env) -> Option<u32> {
+    let key = StorageKey::RecorderBudget;
+    e.storage().instance().get(&key)
+}
+
+/// Set or remove the daily points budget of each recorder
+pub fn set_recorder_budget(e: &Env, budget: Option<u32>) {
+    let key = StorageKey::RecorderBudget;
+    match budget {
+        Some(budget) => e.storage().instance().set(&key, &budget),
+        None => e.storage().instance().remove(&key),
+    }
+}
+
+/// Get how events over a limit are handled (defaults to rejecting them)
+pub fn get_limit_mode(e: &Env) -> LimitMode {
+    let key = StorageKey::LimitMode;
+    e.storage().instance().get(&key).unwrap_or(LimitMode::Reject)
+}
+
+/// Set how events over a limit are handled
+pub fn set_limit_mode(e: &Env, mode: LimitMode) {
+    let key = StorageKey::LimitMode;
+    e.storage().instance().set(&key, &mode);
+}
+
+/// Get the number of users kept on each leaderboard
+pub fn get_leaderboard_size(e: &Env) -> u32 {
+    let key = StorageKey::LeaderboardSize;
+    e.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(DEFAULT_LEADERBOARD_SIZE)
+}
+
+/// Set the number of users kept on each leaderboard
+pub fn set_leaderboard_size(e: &Env, size: u32) {
+    let key = StorageKey::LeaderboardSize;
+    e.storage().instance().set(&key, &size);
+}
+
+/// Get the scheduled and running bonus campaigns
+pub fn get_bonus_campaigns(e: &Env) -> Map<u32, BonusCampaign> {
+    let key = StorageKey::BonusCampaigns;
+    e.storage().instance().get(&key).unwrap_or(Map::new(e))
+}
+
+/// Set the scheduled and running bonus campaigns
+pub fn set_bonus_campaigns(e: &Env, campaigns: &Map<u32, BonusCampaign>) {
+    let key = StorageKey::BonusCampaigns;
+    e.storage().instance().set(&key, campaigns);
+}
+
+/// Take the next bonus campaign id (ids start at 1)
+pub fn next_bonus_campaign_id(e: &Env) -> u32 {
+    let key = StorageKey::NextBonusCampaignId;
+    let id: u32 = e.storage().instance().get(&key).unwrap_or(1);
+    e.storage().instance().set(&key, &(id + 1));
+    id
+}
+
+// ============================================================================
+// Merge Proposal Storage
+// ============================================================================
+
+/// Get the pending proposal to merge an account into another
+pub fn get_merge_proposal(e: &Env, source: &Address) -> Option<MergeProposal> {
+    let key = StorageKey::MergeProposal(source.clone());
+    let value: Option<MergeProposal> = e.storage().persistent().get(&key);
+    if value.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+    }
+    value
+}
+
+/// Set the pending proposal to merge an account into another
+pub fn set_merge_proposal(e: &Env, proposal: &MergeProposal) {
+    let key = StorageKey::MergeProposal(proposal.source.clone());
+    e.storage().persistent().set(&key, proposal);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_AMOUNT);
+}
+
+/// Remove the pending proposal to merge an account into another
+pub fn remove_merge_proposal(e: &Env, source: &Address) {
+    let key = StorageKey::MergeProposal(source.clone());
+    e.storage().persistent().remove(&key);
+}
+
+// ============================================================================
+// Attester Storage
+// ============================================================================
+
+/// Get the ed25519 public key that signs attestations
+pub fn get_attester(e: &Env) -> Option<BytesN<32>> {
+    let key = StorageKey::AttesterKey;
+    e.storage().instance().get(&key)
+}
+
+/// Set or remove the ed25519 public key that signs attestations
+pub fn set_attester(e: &Env, public_key: &Option<BytesN<32>>) {
+    let key = StorageKey::AttesterKey;
+    match public_key {
+        Some(public_key) => e.storage().instance().set(&key, public_key),
+        None => e.storage().instance().remove(&key),
+    }
+}
+
 // ============================================================================
 // Initialization Storage
 // ============================================================================
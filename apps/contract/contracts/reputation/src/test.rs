@@ -1,9 +1,18 @@
 #![cfg(test)]
 extern crate std;
 
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, Map};
-
-use crate::types::{EventType, Level, ThresholdType};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, Map, String, Vec,
+};
+
+use crate::nft_client::{NFTAttribute, NFTMetadata};
+use crate::types::{
+    Attestation, DecayPolicy, DeductionReason, EventType, EventTypeConfig, Level, LevelDefinition,
+    LevelTemplate, LimitMode, RateLimit, ThresholdType, MAX_ATTESTATION_VALIDITY, MERGE_TIME_LOCK,
+};
 use crate::{Reputation, ReputationClient};
 
 /// Helper to create a test environment with an initialized contract.
@@ -47,6 +56,30 @@ impl TestEnv {
         self.client
             .grant_role(account, &symbol_short!("config"), &self.admin);
     }
+
+    fn grant_moderator_role(&self, account: &Address) {
+        self.client
+            .grant_role(account, &symbol_short!("moderator"), &self.admin);
+    }
+
+    /// NFT level attribute for a level of the current level table.
+    fn level_attribute(&self, level: Level) -> NFTAttribute {
+        let level = self
+            .client
+            .get_level_table()
+            .iter()
+            .find(|definition| definition.id == level.as_u32())
+            .unwrap();
+        crate::nft_client::build_level_attribute(&self.env, &level)
+    }
+
+    fn level_definition(&self, id: u32, name: &str, threshold: u32) -> LevelDefinition {
+        LevelDefinition {
+            id,
+            name: String::from_str(&self.env, name),
+            threshold,
+        }
+    }
 }
 
 // ============================================================================
@@ -102,7 +135,7 @@ fn test_record_event_adds_points() {
     test.grant_recorder_role(&recorder);
 
     // Record a donation event (default 10 points)
-    let new_total = test.client.record_event(&recorder, &user, &EventType::Donation);
+    let new_total = test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
 
     assert_eq!(new_total, 10);
     assert_eq!(test.client.get_points(&user), 10);
@@ -117,9 +150,9 @@ fn test_record_multiple_events() {
     test.grant_recorder_role(&recorder);
 
     // Record multiple events
-    test.client.record_event(&recorder, &user, &EventType::Donation); // 10 points
-    test.client.record_event(&recorder, &user, &EventType::StreakDonation); // 25 points
-    test.client.record_event(&recorder, &user, &EventType::SuccessfulReferral); // 50 points
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None); // 10 points
+    test.client.record_event(&recorder, &user, &EventType::StreakDonation.as_u32(), &None); // 25 points
+    test.client.record_event(&recorder, &user, &EventType::SuccessfulReferral.as_u32(), &None); // 50 points
 
     assert_eq!(test.client.get_points(&user), 85);
 }
@@ -134,7 +167,7 @@ fn test_record_event_with_custom_points() {
 
     let new_total =
         test.client
-            .record_event_with_points(&recorder, &user, &EventType::Donation, &100);
+            .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &100, &None);
 
     assert_eq!(new_total, 100);
     assert_eq!(test.client.get_points(&user), 100);
@@ -150,7 +183,7 @@ fn test_record_event_with_zero_points_fails() {
     test.grant_recorder_role(&recorder);
 
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &0);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &0, &None);
 }
 
 #[test]
@@ -162,7 +195,83 @@ fn test_record_event_without_role_fails() {
 
     // Should fail - unauthorized doesn't have recorder role
     test.client
-        .record_event(&unauthorized, &user, &EventType::Donation);
+        .record_event(&unauthorized, &user, &EventType::Donation.as_u32(), &None);
+}
+
+#[test]
+fn test_record_event_retry_with_same_reference_is_idempotent() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    let donation_tx = Some(BytesN::from_array(&test.env, &[1u8; 32]));
+    test.grant_recorder_role(&recorder);
+
+    let first = test
+        .client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &donation_tx);
+    let retry = test
+        .client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &donation_tx);
+
+    assert_eq!(first, 10);
+    assert_eq!(retry, 10);
+    assert_eq!(test.client.get_points(&user), 10);
+
+    let events = test.client.get_user_events(&user, &0, &50);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.get(0).unwrap().external_ref, donation_tx);
+}
+
+#[test]
+fn test_retry_returns_original_total_after_other_events() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    let donation_tx = Some(BytesN::from_array(&test.env, &[1u8; 32]));
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &100, &donation_tx);
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+
+    let retry = test
+        .client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &100, &donation_tx);
+
+    assert_eq!(retry, 100);
+    assert_eq!(test.client.get_points(&user), 110);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #411)")] // DuplicateReference
+fn test_reference_reused_for_other_user_fails() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    let other_user = Address::generate(&test.env);
+    let donation_tx = Some(BytesN::from_array(&test.env, &[1u8; 32]));
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &donation_tx);
+    test.client
+        .record_event(&recorder, &other_user, &EventType::Donation.as_u32(), &donation_tx);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #411)")] // DuplicateReference
+fn test_reference_reused_with_other_points_fails() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    let donation_tx = Some(BytesN::from_array(&test.env, &[1u8; 32]));
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &100, &donation_tx);
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &200, &donation_tx);
 }
 
 // ============================================================================
@@ -182,7 +291,7 @@ fn test_level_up_to_bronze() {
 
     // Add 200 points to reach Bronze (using custom points)
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &200);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &200, &None);
 
     assert_eq!(test.client.get_level(&user), Level::Bronze.as_u32());
 }
@@ -197,7 +306,7 @@ fn test_level_up_to_silver() {
 
     // Add 500 points to reach Silver
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &500);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &500, &None);
 
     assert_eq!(test.client.get_level(&user), Level::Silver.as_u32());
 }
@@ -212,7 +321,7 @@ fn test_level_up_to_gold() {
 
     // Add 1000 points to reach Gold
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &1000);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &1000, &None);
 
     assert_eq!(test.client.get_level(&user), Level::Gold.as_u32());
 }
@@ -227,7 +336,7 @@ fn test_level_up_to_diamond() {
 
     // Add 5000 points to reach Diamond
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &5000);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &5000, &None);
 
     assert_eq!(test.client.get_level(&user), Level::Diamond.as_u32());
 }
@@ -248,6 +357,318 @@ fn test_calculate_level() {
     assert_eq!(test.client.calculate_level(&10000), Level::Diamond.as_u32());
 }
 
+// ============================================================================
+// Deduction Tests
+// ============================================================================
+
+#[test]
+fn test_deduct_points() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &100, &None);
+    let new_total = test.client.deduct_points(
+        &moderator,
+        &user,
+        &40,
+        &EventType::Donation.as_u32(),
+        &DeductionReason::RefundedDonation,
+    );
+
+    assert_eq!(new_total, 60);
+    assert_eq!(test.client.get_points(&user), 60);
+
+    let events = test.client.get_user_events(&user, &0, &50);
+    assert_eq!(events.len(), 2);
+    let deduction = events.get(1).unwrap();
+    assert_eq!(deduction.points, 40);
+    assert_eq!(
+        deduction.deduction_reason,
+        Some(DeductionReason::RefundedDonation)
+    );
+}
+
+#[test]
+fn test_deduct_points_does_not_underflow() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    let new_total = test.client.deduct_points(
+        &moderator,
+        &user,
+        &1000,
+        &EventType::Donation.as_u32(),
+        &DeductionReason::Fraud,
+    );
+
+    assert_eq!(new_total, 0);
+    // Only the points the user actually had are recorded as deducted
+    assert_eq!(test.client.get_user_events(&user, &0, &50).get(1).unwrap().points, 10);
+}
+
+#[test]
+fn test_deduct_points_lowers_level_and_permissions() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &250, &None);
+    assert_eq!(test.client.get_level(&user), Level::Bronze.as_u32());
+    assert!(test.client.meets_threshold(&user, &ThresholdType::Voting));
+
+    test.client.deduct_points(
+        &moderator,
+        &user,
+        &100,
+        &EventType::Donation.as_u32(),
+        &DeductionReason::Fraud,
+    );
+
+    assert_eq!(test.client.get_level(&user), Level::Rookie.as_u32());
+    assert!(!test.client.meets_threshold(&user, &ThresholdType::Voting));
+}
+
+#[test]
+fn test_deduct_points_downgrades_nft() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &250, &None);
+    test.client.deduct_points(
+        &moderator,
+        &user,
+        &100,
+        &EventType::Donation.as_u32(),
+        &DeductionReason::Fraud,
+    );
+
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+    let metadata = nft.get_metadata(&token_id).unwrap();
+    assert!(metadata
+        .attributes
+        .contains(test.level_attribute(Level::Rookie)));
+    assert!(metadata
+        .attributes
+        .contains(crate::nft_client::build_points_attribute(&test.env, 150)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")] // Role check failure
+fn test_deduct_points_without_role_fails() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client.deduct_points(
+        &recorder,
+        &user,
+        &10,
+        &EventType::Donation.as_u32(),
+        &DeductionReason::Fraud,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #404)")] // InvalidPoints
+fn test_deduct_zero_points_fails() {
+    let test = TestEnv::new();
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_moderator_role(&moderator);
+
+    test.client.deduct_points(
+        &moderator,
+        &user,
+        &0,
+        &EventType::Donation.as_u32(),
+        &DeductionReason::Correction,
+    );
+}
+
+// ============================================================================
+// Decay Tests
+// ============================================================================
+
+const DAY_IN_SECONDS: u64 = 86_400;
+
+impl TestEnv {
+    /// Configure 10% decay per day of inactivity.
+    fn set_daily_decay(&self) {
+        let config = Address::generate(&self.env);
+        self.grant_config_role(&config);
+        self.client.set_decay_policy(
+            &config,
+            &Some(DecayPolicy {
+                period_seconds: DAY_IN_SECONDS,
+                decay_bps: 1000,
+            }),
+        );
+    }
+}
+
+#[test]
+fn test_decay_applies_on_read() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.set_daily_decay();
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &1000, &None);
+    assert_eq!(test.client.get_level(&user), Level::Gold.as_u32());
+
+    // Two full days of inactivity: 1000 -> 900 -> 810
+    test.env.ledger().set_timestamp(2 * DAY_IN_SECONDS + 10);
+
+    assert_eq!(test.client.get_points(&user), 810);
+    assert_eq!(test.client.get_level(&user), Level::Silver.as_u32());
+    assert!(!test
+        .client
+        .meets_threshold(&user, &ThresholdType::ExclusiveRounds));
+
+    let breakdown = test.client.get_points_breakdown(&user);
+    assert_eq!(breakdown.raw_points, 1000);
+    assert_eq!(breakdown.effective_points, 810);
+    assert_eq!(breakdown.decayed_points, 190);
+    assert_eq!(breakdown.last_activity, Some(0));
+}
+
+#[test]
+fn test_decay_persists_on_write() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.set_daily_decay();
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &1000, &None);
+    test.env.ledger().set_timestamp(2 * DAY_IN_SECONDS);
+
+    let new_total = test
+        .client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+
+    assert_eq!(new_total, 820);
+    let breakdown = test.client.get_points_breakdown(&user);
+    assert_eq!(breakdown.raw_points, 820);
+    assert_eq!(breakdown.effective_points, 820);
+    assert_eq!(breakdown.last_activity, Some(2 * DAY_IN_SECONDS));
+}
+
+#[test]
+fn test_deduction_does_not_restart_decay() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+    test.set_daily_decay();
+    let donation = EventType::Donation.as_u32();
+
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &1000, &None);
+
+    // 1000 -> 900 -> 810, then 10 deducted
+    test.env.ledger().set_timestamp(2 * DAY_IN_SECONDS + 10);
+    let new_total = test.client.deduct_points(
+        &moderator,
+        &user,
+        &10,
+        &donation,
+        &DeductionReason::RefundedDonation,
+    );
+    assert_eq!(new_total, 800);
+    assert_eq!(
+        test.client.get_points_breakdown(&user).last_activity,
+        Some(2 * DAY_IN_SECONDS)
+    );
+
+    // The third day of inactivity still counts from the donation, and the
+    // two persisted days are not applied again
+    test.env.ledger().set_timestamp(3 * DAY_IN_SECONDS);
+    assert_eq!(test.client.get_points(&user), 720);
+}
+
+#[test]
+fn test_decay_counts_from_history_without_last_activity() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.set_daily_decay();
+
+    test.env.ledger().set_timestamp(DAY_IN_SECONDS);
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &1000, &None);
+
+    // A user recorded before last activity was tracked decays from their
+    // newest history record
+    test.env.as_contract(&test.client.address, || {
+        crate::storage::remove_last_activity(&test.env, &user);
+    });
+    test.env.ledger().set_timestamp(3 * DAY_IN_SECONDS + 10);
+
+    assert_eq!(test.client.get_points(&user), 810);
+    assert_eq!(
+        test.client.get_points_breakdown(&user).last_activity,
+        Some(DAY_IN_SECONDS)
+    );
+}
+
+#[test]
+fn test_no_decay_without_policy() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &1000, &None);
+    test.env.ledger().set_timestamp(365 * DAY_IN_SECONDS);
+
+    assert_eq!(test.client.get_points(&user), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #410)")] // InvalidDecayPolicy
+fn test_set_decay_policy_with_zero_period_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    test.client.set_decay_policy(
+        &config,
+        &Some(DecayPolicy {
+            period_seconds: 0,
+            decay_bps: 1000,
+        }),
+    );
+}
+
 // ============================================================================
 // Threshold Tests
 // ============================================================================
@@ -265,7 +686,7 @@ fn test_meets_threshold_voting() {
 
     // Level up to Bronze
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &200);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &200, &None);
 
     // Now should meet voting threshold
     assert!(test.client.meets_threshold(&user, &ThresholdType::Voting));
@@ -281,7 +702,7 @@ fn test_meets_threshold_early_access() {
 
     // Level up to Silver (500 points)
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &500);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &500, &None);
 
     // Should meet early access threshold (requires Silver)
     assert!(test
@@ -299,7 +720,7 @@ fn test_meets_threshold_exclusive_rounds() {
 
     // Level up to Gold (1000 points)
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &1000);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &1000, &None);
 
     // Should meet exclusive rounds threshold (requires Gold)
     assert!(test
@@ -317,7 +738,7 @@ fn test_meets_threshold_special_rewards() {
 
     // Level up to Diamond (5000 points)
     test.client
-        .record_event_with_points(&recorder, &user, &EventType::Donation, &5000);
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &5000, &None);
 
     // Should meet special rewards threshold (requires Diamond)
     assert!(test
@@ -353,87 +774,1522 @@ fn test_set_level_thresholds() {
 }
 
 #[test]
-fn test_set_event_point_values() {
+fn test_raised_threshold_applies_without_new_event() {
     let test = TestEnv::new();
     let config_user = Address::generate(&test.env);
     let recorder = Address::generate(&test.env);
     let user = Address::generate(&test.env);
-
     test.grant_config_role(&config_user);
     test.grant_recorder_role(&recorder);
 
-    // Set new event point values
-    let mut event_points: Map<EventType, u32> = Map::new(&test.env);
-    event_points.set(EventType::Donation, 50); // Increase donation points
-
-    test.client
-        .set_event_point_values(&config_user, &event_points);
-
-    // Verify new point value
-    assert_eq!(
-        test.client.get_event_point_value(&EventType::Donation),
-        50
+    test.client.record_event_with_points(
+        &recorder,
+        &user,
+        &EventType::Donation.as_u32(),
+        &600,
+        &None,
     );
+    assert_eq!(test.client.get_level(&user), Level::Silver.as_u32());
+    assert!(test
+        .client
+        .meets_threshold(&user, &ThresholdType::EarlyAccess));
 
-    // Record event should use new point value
-    let points = test.client.record_event(&recorder, &user, &EventType::Donation);
-    assert_eq!(points, 50);
+    let mut thresholds: Map<u32, u32> = Map::new(&test.env);
+    thresholds.set(Level::Silver.as_u32(), 700);
+    test.client.set_level_thresholds(&config_user, &thresholds);
+
+    assert_eq!(test.client.get_level(&user), Level::Bronze.as_u32());
+    assert!(!test
+        .client
+        .meets_threshold(&user, &ThresholdType::EarlyAccess));
+    assert!(test.client.meets_threshold(&user, &ThresholdType::Voting));
 }
 
 #[test]
-fn test_set_permission_threshold() {
+#[should_panic(expected = "Error(Contract, #414)")] // UnknownLevel
+fn test_set_level_thresholds_unknown_level_fails() {
     let test = TestEnv::new();
     let config_user = Address::generate(&test.env);
-    let recorder = Address::generate(&test.env);
-    let user = Address::generate(&test.env);
-
     test.grant_config_role(&config_user);
-    test.grant_recorder_role(&recorder);
 
-    // Set voting threshold to Rookie (anyone can vote)
-    test.client.set_permission_threshold(
-        &config_user,
-        &ThresholdType::Voting,
-        &Level::Rookie.as_u32(),
-    );
+    let mut thresholds: Map<u32, u32> = Map::new(&test.env);
+    thresholds.set(7, 100);
 
-    // New user (Rookie) should now meet voting threshold
-    assert!(test.client.meets_threshold(&user, &ThresholdType::Voting));
+    test.client.set_level_thresholds(&config_user, &thresholds);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2000)")] // Role check failure
-fn test_set_level_thresholds_without_role_fails() {
+#[should_panic(expected = "Error(Contract, #406)")] // InvalidLevelThreshold
+fn test_set_level_thresholds_out_of_order_fails() {
     let test = TestEnv::new();
-    let unauthorized = Address::generate(&test.env);
+    let config_user = Address::generate(&test.env);
+    test.grant_config_role(&config_user);
 
-    let thresholds: Map<u32, u32> = Map::new(&test.env);
+    // Bronze above Silver (500)
+    let mut thresholds: Map<u32, u32> = Map::new(&test.env);
+    thresholds.set(Level::Bronze.as_u32(), 600);
 
-    test.client
-        .set_level_thresholds(&unauthorized, &thresholds);
+    test.client.set_level_thresholds(&config_user, &thresholds);
 }
 
-// ============================================================================
-// Event History Tests
-// ============================================================================
-
 #[test]
-fn test_get_user_events() {
+fn test_set_level_table_custom_levels() {
+    let test = TestEnv::new();
+    let config_user = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_config_role(&config_user);
+    test.grant_recorder_role(&recorder);
+
+    let levels = vec![
+        &test.env,
+        test.level_definition(0, "seed", 0),
+        test.level_definition(1, "sprout", 50),
+        test.level_definition(2, "tree", 150),
+        test.level_definition(10, "forest", 400),
+    ];
+    test.client.set_level_table(&config_user, &levels);
+    assert_eq!(test.client.get_level_table(), levels);
+
+    assert_eq!(test.client.calculate_level(&49), 0);
+    assert_eq!(test.client.calculate_level(&400), 10);
+    assert_eq!(test.client.get_level_threshold(&10), 400);
+
+    test.client
+        .set_permission_threshold(&config_user, &ThresholdType::Voting, &10);
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &399, &None);
+    assert_eq!(test.client.get_level(&user), 2);
+    assert!(!test.client.meets_threshold(&user, &ThresholdType::Voting));
+
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    assert_eq!(test.client.get_level(&user), 10);
+    assert!(test.client.meets_threshold(&user, &ThresholdType::Voting));
+}
+
+#[test]
+fn test_custom_level_name_on_nft() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let config_user = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_config_role(&config_user);
+    test.grant_recorder_role(&recorder);
+
+    let levels = vec![
+        &test.env,
+        test.level_definition(0, "seed", 0),
+        test.level_definition(1, "sprout", 50),
+    ];
+    test.client.set_level_table(&config_user, &levels);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &50, &None);
+
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+    let metadata = nft.get_metadata(&token_id).unwrap();
+    assert!(metadata.attributes.contains(NFTAttribute {
+        trait_type: String::from_str(&test.env, "level"),
+        value: String::from_str(&test.env, "sprout"),
+        display_type: Some(String::from_str(&test.env, "string")),
+        max_value: None,
+    }));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #406)")] // InvalidLevelThreshold
+fn test_set_level_table_non_increasing_fails() {
+    let test = TestEnv::new();
+    let config_user = Address::generate(&test.env);
+    test.grant_config_role(&config_user);
+
+    let levels = vec![
+        &test.env,
+        test.level_definition(0, "seed", 0),
+        test.level_definition(1, "sprout", 100),
+        test.level_definition(2, "tree", 100),
+    ];
+    test.client.set_level_table(&config_user, &levels);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #414)")] // UnknownLevel
+fn test_set_permission_threshold_unknown_level_fails() {
+    let test = TestEnv::new();
+    let config_user = Address::generate(&test.env);
+    test.grant_config_role(&config_user);
+
+    test.client
+        .set_permission_threshold(&config_user, &ThresholdType::Voting, &5);
+}
+
+#[test]
+fn test_set_event_point_values() {
+    let test = TestEnv::new();
+    let config_user = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_config_role(&config_user);
+    test.grant_recorder_role(&recorder);
+
+    // Set new event point values
+    let mut event_points: Map<u32, u32> = Map::new(&test.env);
+    event_points.set(EventType::Donation.as_u32(), 50); // Increase donation points
+
+    test.client
+        .set_event_point_values(&config_user, &event_points);
+
+    // Verify new point value
+    assert_eq!(
+        test.client.get_event_point_value(&EventType::Donation.as_u32()),
+        50
+    );
+
+    // Record event should use new point value
+    let points = test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    assert_eq!(points, 50);
+}
+
+#[test]
+fn test_set_permission_threshold() {
+    let test = TestEnv::new();
+    let config_user = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_config_role(&config_user);
+    test.grant_recorder_role(&recorder);
+
+    // Set voting threshold to Rookie (anyone can vote)
+    test.client.set_permission_threshold(
+        &config_user,
+        &ThresholdType::Voting,
+        &Level::Rookie.as_u32(),
+    );
+
+    // New user (Rookie) should now meet voting threshold
+    assert!(test.client.meets_threshold(&user, &ThresholdType::Voting));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")] // Role check failure
+fn test_set_level_thresholds_without_role_fails() {
+    let test = TestEnv::new();
+    let unauthorized = Address::generate(&test.env);
+
+    let thresholds: Map<u32, u32> = Map::new(&test.env);
+
+    test.client
+        .set_level_thresholds(&unauthorized, &thresholds);
+}
+
+// ============================================================================
+// Event Type Registry Tests
+// ============================================================================
+
+const ACADEMY_LESSON: u32 = 100;
+
+impl TestEnv {
+    fn event_type_config(
+        &self,
+        name: &str,
+        default_points: u32,
+        cooldown_seconds: u64,
+    ) -> EventTypeConfig {
+        EventTypeConfig {
+            name: String::from_str(&self.env, name),
+            default_points,
+            enabled: true,
+            cooldown_seconds,
+        }
+    }
+}
+
+#[test]
+fn test_builtin_event_types_registered() {
+    let test = TestEnv::new();
+
+    let event_types = test.client.get_event_types();
+    assert_eq!(event_types.len(), 8);
+
+    let donation = test.client.get_event_type(&EventType::Donation.as_u32()).unwrap();
+    assert_eq!(donation.name, String::from_str(&test.env, "donation"));
+    assert_eq!(donation.default_points, 10);
+    assert!(donation.enabled);
+    assert_eq!(donation.cooldown_seconds, 0);
+
+    assert!(test.client.get_event_type(&ACADEMY_LESSON).is_none());
+}
+
+#[test]
+fn test_record_custom_event_type() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+
+    test.client.set_event_type(
+        &config,
+        &ACADEMY_LESSON,
+        &test.event_type_config("academy_lesson", 15, 0),
+    );
+
+    let new_total = test.client.record_event(&recorder, &user, &ACADEMY_LESSON, &None);
+    assert_eq!(new_total, 15);
+    assert_eq!(test.client.get_event_types().len(), 9);
+    assert_eq!(
+        test.client.get_user_events(&user, &0, &50).get(0).unwrap().event_type,
+        ACADEMY_LESSON
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #403)")] // InvalidEventType
+fn test_record_unregistered_event_type_fails() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client.record_event(&recorder, &user, &ACADEMY_LESSON, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #415)")] // EventTypeDisabled
+fn test_record_disabled_event_type_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+
+    let mut donation = test.client.get_event_type(&EventType::Donation.as_u32()).unwrap();
+    donation.enabled = false;
+    test.client
+        .set_event_type(&config, &EventType::Donation.as_u32(), &donation);
+
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+}
+
+#[test]
+fn test_event_type_cooldown_elapses() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    let other_user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+
+    test.client.set_event_type(
+        &config,
+        &ACADEMY_LESSON,
+        &test.event_type_config("academy_lesson", 15, 3600),
+    );
+
+    test.client.record_event(&recorder, &user, &ACADEMY_LESSON, &None);
+    // The cooldown is per user
+    test.client.record_event(&recorder, &other_user, &ACADEMY_LESSON, &None);
+
+    test.env.ledger().set_timestamp(3600);
+    let new_total = test.client.record_event(&recorder, &user, &ACADEMY_LESSON, &None);
+    assert_eq!(new_total, 30);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #416)")] // EventCooldownActive
+fn test_event_type_cooldown_blocks_repeat() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+
+    test.client.set_event_type(
+        &config,
+        &ACADEMY_LESSON,
+        &test.event_type_config("academy_lesson", 15, 3600),
+    );
+
+    test.client.record_event(&recorder, &user, &ACADEMY_LESSON, &None);
+    test.env.ledger().set_timestamp(3599);
+    test.client
+        .record_event_with_points(&recorder, &user, &ACADEMY_LESSON, &5, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #417)")] // TooManyEventTypes
+fn test_custom_event_type_limit() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    for id in 0..33u32 {
+        test.client.set_event_type(
+            &config,
+            &(ACADEMY_LESSON + id),
+            &test.event_type_config("custom", 1, 0),
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #403)")] // InvalidEventType
+fn test_set_point_value_for_unregistered_type_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    let mut event_points: Map<u32, u32> = Map::new(&test.env);
+    event_points.set(ACADEMY_LESSON, 20);
+    test.client.set_event_point_values(&config, &event_points);
+}
+
+// ============================================================================
+// Rate Limit Tests
+// ============================================================================
+
+impl TestEnv {
+    /// Limit donations per user per day; returns (config, recorder)
+    fn set_daily_donation_limit(&self, max_events: u32, max_points: u32) -> (Address, Address) {
+        let config = Address::generate(&self.env);
+        let recorder = Address::generate(&self.env);
+        self.grant_config_role(&config);
+        self.grant_recorder_role(&recorder);
+
+        self.client.set_rate_limit(
+            &config,
+            &EventType::Donation.as_u32(),
+            &Some(RateLimit {
+                period_seconds: DAY_IN_SECONDS,
+                max_events,
+                max_points,
+            }),
+        );
+        (config, recorder)
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #418)")] // RateLimitExceeded
+fn test_rate_limit_rejects_extra_events() {
+    let test = TestEnv::new();
+    let (_, recorder) = test.set_daily_donation_limit(2, 0);
+    let user = Address::generate(&test.env);
+    let donation = EventType::Donation.as_u32();
+
+    test.client.record_event(&recorder, &user, &donation, &None);
+    test.client.record_event(&recorder, &user, &donation, &None);
+    test.client.record_event(&recorder, &user, &donation, &None);
+}
+
+#[test]
+fn test_rate_limit_resets_in_next_window() {
+    let test = TestEnv::new();
+    let (_, recorder) = test.set_daily_donation_limit(2, 0);
+    let user = Address::generate(&test.env);
+    let other_user = Address::generate(&test.env);
+    let donation = EventType::Donation.as_u32();
+
+    test.client.record_event(&recorder, &user, &donation, &None);
+    test.client.record_event(&recorder, &user, &donation, &None);
+
+    // Limits are per user and per event type
+    test.client.record_event(&recorder, &other_user, &donation, &None);
+    test.client
+        .record_event(&recorder, &user, &EventType::StreakDonation.as_u32(), &None);
+
+    test.env.ledger().set_timestamp(DAY_IN_SECONDS);
+    let new_total = test.client.record_event(&recorder, &user, &donation, &None);
+    assert_eq!(new_total, 55);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #418)")] // RateLimitExceeded
+fn test_rate_limit_caps_points() {
+    let test = TestEnv::new();
+    let (_, recorder) = test.set_daily_donation_limit(0, 25);
+    let user = Address::generate(&test.env);
+    let donation = EventType::Donation.as_u32();
+
+    test.client.record_event(&recorder, &user, &donation, &None);
+    test.client.record_event(&recorder, &user, &donation, &None);
+    // 30 points would exceed the cap of 25
+    test.client.record_event(&recorder, &user, &donation, &None);
+}
+
+#[test]
+fn test_zero_points_mode_records_event_without_points() {
+    let test = TestEnv::new();
+    let (config, recorder) = test.set_daily_donation_limit(1, 0);
+    let user = Address::generate(&test.env);
+    let donation = EventType::Donation.as_u32();
+
+    test.client.set_limit_mode(&config, &LimitMode::ZeroPoints);
+    assert_eq!(test.client.get_limit_mode(), LimitMode::ZeroPoints);
+
+    test.client.record_event(&recorder, &user, &donation, &None);
+    let new_total = test.client.record_event_with_points(&recorder, &user, &donation, &500, &None);
+
+    assert_eq!(new_total, 10);
+    assert_eq!(test.client.get_user_event_count(&user), 2);
+    assert_eq!(test.client.get_user_events(&user, &1, &1).get(0).unwrap().points, 0);
+}
+
+#[test]
+fn test_recorder_budget_is_per_recorder() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let other_recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+    test.grant_recorder_role(&other_recorder);
+
+    test.client.set_recorder_budget(&config, &Some(100));
+    let donation = EventType::Donation.as_u32();
+
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &90, &None);
+    assert_eq!(test.client.get_recorder_budget_remaining(&recorder), Some(10));
+
+    let result = test
+        .client
+        .try_record_event_with_points(&recorder, &user, &donation, &11, &None);
+    assert!(result.is_err());
+
+    test.client
+        .record_event_with_points(&other_recorder, &user, &donation, &100, &None);
+    assert_eq!(test.client.get_recorder_budget_remaining(&other_recorder), Some(0));
+
+    // The budget resets daily
+    test.env.ledger().set_timestamp(DAY_IN_SECONDS);
+    assert_eq!(test.client.get_recorder_budget_remaining(&recorder), Some(100));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #419)")] // RecorderBudgetExceeded
+fn test_recorder_budget_exceeded_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+
+    test.client.set_recorder_budget(&config, &Some(15));
+    let donation = EventType::Donation.as_u32();
+
+    test.client.record_event(&recorder, &user, &donation, &None);
+    test.client.record_event(&recorder, &user, &donation, &None);
+}
+
+#[test]
+fn test_get_remaining_allowance() {
+    let test = TestEnv::new();
+    let (_, recorder) = test.set_daily_donation_limit(3, 0);
+    let user = Address::generate(&test.env);
+    let donation = EventType::Donation.as_u32();
+
+    assert!(test
+        .client
+        .get_remaining_allowance(&user, &EventType::StreakDonation.as_u32())
+        .is_none());
+
+    test.client.record_event(&recorder, &user, &donation, &None);
+
+    let allowance = test.client.get_remaining_allowance(&user, &donation).unwrap();
+    assert_eq!(allowance.events_remaining, Some(2));
+    assert_eq!(allowance.points_remaining, None);
+    assert_eq!(allowance.resets_at, DAY_IN_SECONDS);
+    assert_eq!(test.client.get_recorder_budget_remaining(&recorder), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #420)")] // InvalidRateLimit
+fn test_set_rate_limit_with_zero_period_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    test.client.set_rate_limit(
+        &config,
+        &EventType::Donation.as_u32(),
+        &Some(RateLimit {
+            period_seconds: 0,
+            max_events: 1,
+            max_points: 0,
+        }),
+    );
+}
+
+// ============================================================================
+// Checkpoint Tests
+// ============================================================================
+
+#[test]
+fn test_points_and_level_at_past_ledgers() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+    let donation = EventType::Donation.as_u32();
+
+    test.env.ledger().set_sequence_number(10);
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &150, &None);
+    test.env.ledger().set_sequence_number(20);
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &400, &None);
+    test.env.ledger().set_sequence_number(30);
+    test.client
+        .deduct_points(&moderator, &user, &100, &donation, &DeductionReason::Fraud);
+    test.env.ledger().set_sequence_number(40);
+
+    assert_eq!(test.client.get_points_at(&user, &9), 0);
+    assert_eq!(test.client.get_level_at(&user, &9), Level::Rookie.as_u32());
+    assert_eq!(test.client.get_points_at(&user, &10), 150);
+    assert_eq!(test.client.get_points_at(&user, &25), 550);
+    assert_eq!(test.client.get_level_at(&user, &25), Level::Silver.as_u32());
+    assert_eq!(test.client.get_points_at(&user, &39), 450);
+    assert_eq!(test.client.get_level_at(&user, &39), Level::Bronze.as_u32());
+}
+
+#[test]
+fn test_checkpoint_keeps_last_change_in_ledger() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    test.env.ledger().set_sequence_number(10);
+    test.client.record_event(&recorder, &user, &donation, &None);
+    test.client.record_event(&recorder, &user, &donation, &None);
+    test.env.ledger().set_sequence_number(11);
+
+    assert_eq!(test.client.get_points_at(&user, &10), 20);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #424)")] // LedgerNotFinalized
+fn test_points_at_current_ledger_fails() {
+    let test = TestEnv::new();
+    let user = Address::generate(&test.env);
+    test.env.ledger().set_sequence_number(10);
+
+    test.client.get_points_at(&user, &10);
+}
+
+// ============================================================================
+// Season Tests
+// ============================================================================
+
+impl TestEnv {
+    /// Start a season; returns (config, recorder)
+    fn start_season(&self, name: &str) -> (Address, Address) {
+        let config = Address::generate(&self.env);
+        let recorder = Address::generate(&self.env);
+        self.grant_config_role(&config);
+        self.grant_recorder_role(&recorder);
+
+        self.client
+            .start_season(&config, &String::from_str(&self.env, name));
+        (config, recorder)
+    }
+}
+
+#[test]
+fn test_season_tracks_points_alongside_lifetime() {
+    let test = TestEnv::new();
+    let user = Address::generate(&test.env);
+    let donation = EventType::Donation.as_u32();
+
+    assert!(test.client.get_active_season().is_none());
+    let (config, recorder) = test.start_season("Season 1");
+
+    let season = test.client.get_active_season().unwrap();
+    assert_eq!(season.id, 1);
+    assert_eq!(season.name, String::from_str(&test.env, "Season 1"));
+
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &300, &None);
+    test.client.end_season(&config);
+
+    // Points between seasons only count toward the lifetime total
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &50, &None);
+
+    let season_id = test
+        .client
+        .start_season(&config, &String::from_str(&test.env, "Season 2"));
+    assert_eq!(season_id, 2);
+    test.client.record_event(&recorder, &user, &donation, &None);
+
+    assert_eq!(test.client.get_points(&user), 360);
+    assert_eq!(test.client.get_season_points(&1, &user), 300);
+    assert_eq!(test.client.get_season_points(&2, &user), 10);
+}
+
+#[test]
+fn test_season_standing_is_frozen_at_season_end() {
+    let test = TestEnv::new();
+    let user = Address::generate(&test.env);
+    let (config, recorder) = test.start_season("Season 3");
+
+    test.client.record_event_with_points(
+        &recorder,
+        &user,
+        &EventType::Donation.as_u32(),
+        &1000,
+        &None,
+    );
+
+    let standing = test.client.get_season_standing(&1, &user);
+    assert_eq!(standing.level.id, Level::Gold.as_u32());
+    assert!(!standing.finalized);
+
+    test.env.ledger().set_timestamp(1000);
+    test.client.end_season(&config);
+    assert_eq!(test.client.get_season(&1).unwrap().end_time, Some(1000));
+
+    // Raising the Gold threshold afterwards doesn't change the final standing
+    let mut thresholds: Map<u32, u32> = Map::new(&test.env);
+    thresholds.set(Level::Gold.as_u32(), 2000);
+    test.client.set_level_thresholds(&config, &thresholds);
+
+    let standing = test.client.get_season_standing(&1, &user);
+    assert_eq!(standing.points, 1000);
+    assert_eq!(standing.level.name, String::from_str(&test.env, "gold"));
+    assert!(standing.finalized);
+    assert_eq!(test.client.get_level(&user), Level::Silver.as_u32());
+}
+
+#[test]
+fn test_deduction_lowers_season_points() {
+    let test = TestEnv::new();
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_moderator_role(&moderator);
+    let (_, recorder) = test.start_season("Season 1");
+    let donation = EventType::Donation.as_u32();
+
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &100, &None);
+    test.client
+        .deduct_points(&moderator, &user, &40, &donation, &DeductionReason::Fraud);
+
+    assert_eq!(test.client.get_season_points(&1, &user), 60);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #421)")] // SeasonAlreadyActive
+fn test_start_season_while_active_fails() {
+    let test = TestEnv::new();
+    let (config, _) = test.start_season("Season 1");
+
+    test.client
+        .start_season(&config, &String::from_str(&test.env, "Season 2"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #422)")] // NoActiveSeason
+fn test_end_season_without_active_season_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    test.client.end_season(&config);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #423)")] // UnknownSeason
+fn test_get_unknown_season_standing_fails() {
+    let test = TestEnv::new();
+    let user = Address::generate(&test.env);
+
+    test.client.get_season_standing(&1, &user);
+}
+
+// ============================================================================
+// Leaderboard Tests
+// ============================================================================
+
+#[test]
+fn test_leaderboard_ranks_users_by_points() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+    let donation = EventType::Donation.as_u32();
+
+    let alice = Address::generate(&test.env);
+    let bob = Address::generate(&test.env);
+    let carol = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &alice, &donation, &100, &None);
+    test.client
+        .record_event_with_points(&recorder, &bob, &donation, &300, &None);
+    test.client
+        .record_event_with_points(&recorder, &carol, &donation, &200, &None);
+    test.client
+        .deduct_points(&moderator, &bob, &250, &donation, &DeductionReason::Fraud);
+
+    let board = test.client.get_leaderboard(&0, &10);
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get(0).unwrap().user, carol);
+    assert_eq!(board.get(1).unwrap().user, alice);
+    assert_eq!(board.get(2).unwrap().points, 50);
+
+    assert_eq!(test.client.get_rank(&bob), Some(3));
+    assert_eq!(test.client.get_leaderboard(&1, &1).get(0).unwrap().user, alice);
+    assert_eq!(test.client.get_rank(&Address::generate(&test.env)), None);
+}
+
+#[test]
+fn test_leaderboard_size_bounds_the_board() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    let users = [
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+    for (i, user) in users.iter().enumerate() {
+        let points = 100 * (i as u32 + 1);
+        test.client
+            .record_event_with_points(&recorder, user, &donation, &points, &None);
+    }
+
+    // Shrinking drops the lowest entries right away
+    test.client.set_leaderboard_size(&config, &2);
+    assert_eq!(test.client.get_leaderboard_size(), 2);
+    assert_eq!(test.client.get_leaderboard(&0, &10).len(), 2);
+    assert_eq!(test.client.get_rank(&users[0]), None);
+
+    // A user below the last entry of a full board stays off it
+    let newcomer = Address::generate(&test.env);
+    test.client.record_event(&recorder, &newcomer, &donation, &None);
+    assert_eq!(test.client.get_rank(&newcomer), None);
+}
+
+#[test]
+fn test_season_leaderboard_uses_season_points() {
+    let test = TestEnv::new();
+    let (config, recorder) = test.start_season("Season 1");
+    let donation = EventType::Donation.as_u32();
+
+    let veteran = Address::generate(&test.env);
+    let newcomer = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &veteran, &donation, &50, &None);
+    test.client.end_season(&config);
+
+    test.client
+        .record_event_with_points(&recorder, &veteran, &donation, &1000, &None);
+    test.client
+        .start_season(&config, &String::from_str(&test.env, "Season 2"));
+    test.client
+        .record_event_with_points(&recorder, &newcomer, &donation, &100, &None);
+    test.client.record_event(&recorder, &veteran, &donation, &None);
+
+    assert_eq!(test.client.get_rank(&veteran), Some(1));
+    assert_eq!(test.client.get_season_rank(&2, &newcomer), Some(1));
+    assert_eq!(test.client.get_season_rank(&2, &veteran), Some(2));
+    assert_eq!(test.client.get_season_leaderboard(&2, &0, &10).get(1).unwrap().points, 10);
+    assert_eq!(test.client.get_season_leaderboard(&1, &0, &10).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #425)")] // InvalidLeaderboardSize
+fn test_set_leaderboard_size_zero_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    test.client.set_leaderboard_size(&config, &0);
+}
+
+// ============================================================================
+// Bonus Campaign Tests
+// ============================================================================
+
+#[test]
+fn test_bonus_campaign_multiplies_points_in_window() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    let id =
+        test.client
+            .create_bonus_campaign(&config, &vec![&test.env, donation], &20_000, &100, &200);
+    assert_eq!(
+        test.client.get_bonus_campaign(&id).unwrap().multiplier_bps,
+        20_000
+    );
+
+    // Not running yet
+    assert_eq!(
+        test.client.record_event(&recorder, &user, &donation, &None),
+        10
+    );
+
+    test.env.ledger().set_timestamp(100);
+    assert_eq!(test.client.get_event_multiplier(&donation), 20_000);
+    assert_eq!(
+        test.client.record_event(&recorder, &user, &donation, &None),
+        30
+    );
+    let record = test.client.get_user_events(&user, &0, &50).get(1).unwrap();
+    assert_eq!(record.points, 20);
+    assert_eq!(record.base_points, 10);
+
+    // Other event types are not boosted
+    let referral = EventType::SuccessfulReferral.as_u32();
+    assert_eq!(
+        test.client.record_event(&recorder, &user, &referral, &None),
+        80
+    );
+
+    // Ended campaigns are dropped
+    test.env.ledger().set_timestamp(200);
+    assert_eq!(
+        test.client.record_event(&recorder, &user, &donation, &None),
+        90
+    );
+    assert_eq!(test.client.get_bonus_campaign(&id), None);
+    assert!(test.client.get_bonus_campaigns().is_empty());
+}
+
+#[test]
+fn test_overlapping_bonus_campaigns_use_highest_multiplier() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    let event_types = vec![&test.env, donation];
+    test.client
+        .create_bonus_campaign(&config, &event_types, &15_000, &0, &1000);
+    let triple = test
+        .client
+        .create_bonus_campaign(&config, &event_types, &30_000, &0, &1000);
+
+    assert_eq!(
+        test.client
+            .record_event_with_points(&recorder, &user, &donation, &100, &None),
+        300
+    );
+
+    test.client.cancel_bonus_campaign(&config, &triple);
+    assert_eq!(test.client.get_event_multiplier(&donation), 15_000);
+    assert_eq!(test.client.get_bonus_campaigns().len(), 1);
+}
+
+#[test]
+fn test_bonus_campaign_retry_returns_original_total() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+    let donation_tx = Some(BytesN::from_array(&test.env, &[9u8; 32]));
+
+    test.client
+        .create_bonus_campaign(&config, &vec![&test.env, donation], &20_000, &0, &1000);
+    let first =
+        test.client
+            .record_event_with_points(&recorder, &user, &donation, &100, &donation_tx);
+    let retry =
+        test.client
+            .record_event_with_points(&recorder, &user, &donation, &100, &donation_tx);
+
+    assert_eq!(first, 200);
+    assert_eq!(retry, 200);
+    assert_eq!(test.client.get_user_event_count(&user), 1);
+}
+
+#[test]
+fn test_refresh_bonus_campaigns_announces_start_and_end() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    let event_types = vec![&test.env, EventType::Donation.as_u32()];
+    let id = test
+        .client
+        .create_bonus_campaign(&config, &event_types, &20_000, &100, &200);
+
+    test.env.ledger().set_timestamp(50);
+    test.client.refresh_bonus_campaigns();
+    assert!(!test.client.get_bonus_campaign(&id).unwrap().started);
+
+    // Started without any recorded event
+    test.env.ledger().set_timestamp(100);
+    test.client.refresh_bonus_campaigns();
+    assert!(test.client.get_bonus_campaign(&id).unwrap().started);
+
+    test.env.ledger().set_timestamp(200);
+    test.client.refresh_bonus_campaigns();
+    assert_eq!(test.client.get_bonus_campaign(&id), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #426)")] // InvalidBonusCampaign
+fn test_create_bonus_campaign_with_empty_window_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    let event_types = vec![&test.env, EventType::Donation.as_u32()];
+    test.client
+        .create_bonus_campaign(&config, &event_types, &20_000, &500, &500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #428)")] // UnknownBonusCampaign
+fn test_cancel_unknown_bonus_campaign_fails() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    test.grant_config_role(&config);
+
+    test.client.cancel_bonus_campaign(&config, &1);
+}
+
+// ============================================================================
+// Account Migration Tests
+// ============================================================================
+
+#[test]
+fn test_migrate_user_moves_reputation() {
+    let test = TestEnv::new();
+    let (_config, recorder) = test.start_season("Season 1");
+    let moderator = Address::generate(&test.env);
+    test.grant_moderator_role(&moderator);
+    let donation = EventType::Donation.as_u32();
+
+    let old = Address::generate(&test.env);
+    let new = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &old, &donation, &200, &None);
+    test.client.register_user_nft(&recorder, &old, &7);
+
+    assert_eq!(test.client.migrate_user(&moderator, &old, &new), 200);
+
+    assert_eq!(test.client.get_points(&new), 200);
+    assert_eq!(test.client.get_level(&new), Level::Bronze.as_u32());
+    assert_eq!(test.client.get_user_events(&new, &0, &50).len(), 1);
+    assert_eq!(test.client.get_season_points(&1, &new), 200);
+    assert_eq!(test.client.get_rank(&new), Some(1));
+
+    assert_eq!(test.client.get_points(&old), 0);
+    assert_eq!(test.client.get_level(&old), Level::Rookie.as_u32());
+    assert_eq!(test.client.get_user_event_count(&old), 0);
+    assert_eq!(test.client.get_season_points(&1, &old), 0);
+    assert_eq!(test.client.get_rank(&old), None);
+
+    // The token is still owned by, and bound to, the old account
+    assert_eq!(test.client.get_user_nft_token_id(&old), Some(7));
+    assert!(test.client.get_user_nft_token_id(&new).is_none());
+}
+
+#[test]
+fn test_transfer_after_migration_binds_only_recipient() {
+    let (test, _nft) = TestEnv::new_with_mock_nft();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+    let donation = EventType::Donation.as_u32();
+
+    let old = Address::generate(&test.env);
+    let new = Address::generate(&test.env);
+    let buyer = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &old, &donation, &200, &None);
+    test.client.register_user_nft(&recorder, &old, &7);
+    test.client.migrate_user(&moderator, &old, &new);
+
+    // The old account sells the token instead of moving it to the new one
+    test.client.on_nft_transfer(&7, &old, &Some(buyer.clone()));
+
+    assert!(test.client.get_user_nft_token_id(&old).is_none());
+    assert!(test.client.get_user_nft_token_id(&new).is_none());
+    assert_eq!(test.client.get_user_nft_token_id(&buyer), Some(7));
+
+    // A new account can still receive a token of its own
+    test.client.on_nft_transfer(&8, &buyer, &Some(new.clone()));
+    assert_eq!(test.client.get_user_nft_token_id(&new), Some(8));
+    assert!(test.client.is_sync_pending(&new));
+}
+
+#[test]
+fn test_migrate_user_keeps_ended_season_standings() {
+    let test = TestEnv::new();
+    let (config, recorder) = test.start_season("Season 1");
+    let moderator = Address::generate(&test.env);
+    test.grant_moderator_role(&moderator);
+    let donation = EventType::Donation.as_u32();
+
+    let old = Address::generate(&test.env);
+    let new = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &old, &donation, &200, &None);
+    test.client.end_season(&config);
+    test.client
+        .start_season(&config, &String::from_str(&test.env, "Season 2"));
+    test.client
+        .record_event_with_points(&recorder, &old, &donation, &50, &None);
+
+    test.client.migrate_user(&moderator, &old, &new);
+
+    // The ended season is left as finalized
+    assert_eq!(test.client.get_season_points(&1, &old), 200);
+    assert_eq!(test.client.get_season_points(&1, &new), 0);
+    assert_eq!(test.client.get_season_rank(&1, &old), Some(1));
+    assert_eq!(test.client.get_season_rank(&1, &new), None);
+
+    // The active season moves
+    assert_eq!(test.client.get_season_points(&2, &old), 0);
+    assert_eq!(test.client.get_season_points(&2, &new), 50);
+    assert_eq!(test.client.get_season_rank(&2, &new), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #430)")] // AccountNotEmpty
+fn test_migrate_user_to_account_with_reputation_fails() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+    let donation = EventType::Donation.as_u32();
+
+    let old = Address::generate(&test.env);
+    let new = Address::generate(&test.env);
+    test.client.record_event(&recorder, &old, &donation, &None);
+    test.client.record_event(&recorder, &new, &donation, &None);
+
+    test.client.migrate_user(&moderator, &old, &new);
+}
+
+#[test]
+fn test_merge_users_sums_accounts() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    let source = Address::generate(&test.env);
+    let target = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &source, &donation, &150, &None);
+    test.client
+        .record_event_with_points(&recorder, &target, &donation, &100, &None);
+
+    assert_eq!(test.client.merge_users(&source, &target), 250);
+    assert_eq!(test.env.auths().len(), 2);
+
+    assert_eq!(test.client.get_level(&target), Level::Bronze.as_u32());
+    assert_eq!(test.client.get_points(&source), 0);
+    // The source's history is rolled into the target's summary
+    let summary = test
+        .client
+        .get_user_event_summary(&target)
+        .get(donation)
+        .unwrap();
+    assert_eq!(summary.event_count, 1);
+    assert_eq!(summary.points_awarded, 150);
+    assert_eq!(test.client.get_user_event_count(&target), 1);
+    assert_eq!(test.client.get_leaderboard(&0, &10).len(), 1);
+}
+
+#[test]
+fn test_migration_and_merge_restart_decay() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+    test.set_daily_decay();
+    let donation = EventType::Donation.as_u32();
+
+    let old = Address::generate(&test.env);
+    let new = Address::generate(&test.env);
+    let target = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &old, &donation, &1000, &None);
+    test.client
+        .record_event_with_points(&recorder, &target, &donation, &500, &None);
+
+    // Two days of decay are persisted once: 1000 -> 810
+    test.env.ledger().set_timestamp(2 * DAY_IN_SECONDS + 10);
+    assert_eq!(test.client.migrate_user(&moderator, &old, &new), 810);
+    assert_eq!(test.client.get_points(&new), 810);
+
+    // 810 + (500 -> 405)
+    assert_eq!(test.client.merge_users(&new, &target), 1215);
+    assert_eq!(test.client.get_points(&target), 1215);
+    let breakdown = test.client.get_points_breakdown(&target);
+    assert_eq!(breakdown.last_activity, Some(2 * DAY_IN_SECONDS + 10));
+    assert_eq!(test.client.get_points_breakdown(&new).last_activity, None);
+
+    // Decay goes on from the merge
+    test.env.ledger().set_timestamp(3 * DAY_IN_SECONDS + 10);
+    assert_eq!(test.client.get_points(&target), 1093);
+}
+
+#[test]
+fn test_proposed_merge_executes_after_time_lock() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+
+    let source = Address::generate(&test.env);
+    let target = Address::generate(&test.env);
+    test.client
+        .record_event(&recorder, &source, &EventType::Donation.as_u32(), &None);
+
+    let executable_at = test.client.propose_merge(&moderator, &source, &target);
+    assert_eq!(executable_at, MERGE_TIME_LOCK);
+    assert_eq!(
+        test.client.get_merge_proposal(&source).unwrap().target,
+        target
+    );
+
+    test.env.ledger().set_timestamp(executable_at);
+    assert_eq!(test.client.execute_merge(&moderator, &source), 10);
+    assert!(test.client.get_merge_proposal(&source).is_none());
+    assert_eq!(test.client.get_points(&target), 10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #432)")] // MergeTimeLocked
+fn test_execute_merge_before_time_lock_fails() {
+    let test = TestEnv::new();
+    let moderator = Address::generate(&test.env);
+    test.grant_moderator_role(&moderator);
+
+    let source = Address::generate(&test.env);
+    let target = Address::generate(&test.env);
+    test.client.propose_merge(&moderator, &source, &target);
+
+    test.env.ledger().set_timestamp(MERGE_TIME_LOCK - 1);
+    test.client.execute_merge(&moderator, &source);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #431)")] // UnknownMergeProposal
+fn test_cancelled_merge_cannot_execute() {
+    let test = TestEnv::new();
+    let moderator = Address::generate(&test.env);
+    test.grant_moderator_role(&moderator);
+
+    let source = Address::generate(&test.env);
+    let target = Address::generate(&test.env);
+    test.client.propose_merge(&moderator, &source, &target);
+    test.client.cancel_merge(&source, &source);
+
+    test.env.ledger().set_timestamp(MERGE_TIME_LOCK);
+    test.client.execute_merge(&moderator, &source);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")] // Unauthorized
+fn test_cancel_merge_by_other_account_fails() {
+    let test = TestEnv::new();
+    let moderator = Address::generate(&test.env);
+    test.grant_moderator_role(&moderator);
+
+    let source = Address::generate(&test.env);
+    let target = Address::generate(&test.env);
+    test.client.propose_merge(&moderator, &source, &target);
+
+    test.client
+        .cancel_merge(&Address::generate(&test.env), &source);
+}
+
+// ============================================================================
+// Event History Tests
+// ============================================================================
+
+#[test]
+fn test_get_user_events() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_recorder_role(&recorder);
+
+    // Record some events
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    test.client.record_event(&recorder, &user, &EventType::QuestCompletion.as_u32(), &None);
+
+    let events = test.client.get_user_events(&user, &0, &50);
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(0).unwrap().event_type, EventType::Donation.as_u32());
+    assert_eq!(events.get(1).unwrap().event_type, EventType::QuestCompletion.as_u32());
+}
+
+#[test]
+fn test_get_user_events_pagination() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_recorder_role(&recorder);
+
+    for points in 1..=5u32 {
+        test.client
+            .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &points, &None);
+    }
+
+    assert_eq!(test.client.get_user_event_count(&user), 5);
+
+    let page = test.client.get_user_events(&user, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().points, 2);
+    assert_eq!(page.get(1).unwrap().points, 3);
+
+    // Last page is truncated, past the end is empty
+    assert_eq!(test.client.get_user_events(&user, &4, &10).len(), 1);
+    assert_eq!(test.client.get_user_events(&user, &5, &10).len(), 0);
+}
+
+#[test]
+fn test_history_retention_rolls_up_old_records() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let moderator = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+    test.grant_moderator_role(&moderator);
+    test.client.set_history_retention(&config, &2);
+    assert_eq!(test.client.get_history_retention(), 2);
+
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    test.client
+        .deduct_points(&moderator, &user, &5, &EventType::Donation.as_u32(), &DeductionReason::Correction);
+    test.client.record_event(&recorder, &user, &EventType::QuestCompletion.as_u32(), &None);
+
+    // Total count includes rolled-up records
+    assert_eq!(test.client.get_user_event_count(&user), 4);
+
+    // Only the two most recent records are retained, at their original indexes
+    let events = test.client.get_user_events(&user, &0, &50);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(0).unwrap().deduction_reason, Some(DeductionReason::Correction));
+    assert_eq!(events.get(1).unwrap().event_type, EventType::QuestCompletion.as_u32());
+    assert_eq!(test.client.get_user_events(&user, &3, &1).len(), 1);
+
+    let summary = test.client.get_user_event_summary(&user);
+    assert_eq!(summary.len(), 1);
+    let donations = summary.get(EventType::Donation.as_u32()).unwrap();
+    assert_eq!(donations.event_count, 2);
+    assert_eq!(donations.points_awarded, 20);
+    assert_eq!(donations.points_deducted, 0);
+
+    // Points are unaffected by the rollup
+    assert_eq!(test.client.get_points(&user), 45);
+}
+
+#[test]
+fn test_lowered_retention_trims_history_gradually() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+
+    for _ in 0..10 {
+        test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    }
+    assert_eq!(test.client.get_user_events(&user, &0, &50).len(), 10);
+
+    test.client.set_history_retention(&config, &1);
+
+    // Each write rolls up at most 5 records
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    assert_eq!(test.client.get_user_events(&user, &0, &50).len(), 6);
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    assert_eq!(test.client.get_user_events(&user, &0, &50).len(), 2);
+    test.client.record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    assert_eq!(test.client.get_user_events(&user, &0, &50).len(), 1);
+
+    let summary = test.client.get_user_event_summary(&user);
+    assert_eq!(summary.get(EventType::Donation.as_u32()).unwrap().event_count, 12);
+    assert_eq!(test.client.get_user_event_count(&user), 13);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #412)")]
+fn test_zero_history_retention_rejected() {
+    let test = TestEnv::new();
+    let config = Address::generate(&test.env);
+
+    test.grant_config_role(&config);
+    test.client.set_history_retention(&config, &0);
+}
+
+// ============================================================================
+// Attestation Tests
+// ============================================================================
+
+fn attester_key(env: &Env, seed: u8) -> (SigningKey, BytesN<32>) {
+    let signing_key = SigningKey::from_bytes(&[seed; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    (signing_key, public_key)
+}
+
+fn sign_attestation(
+    test: &TestEnv,
+    signing_key: &SigningKey,
+    attestation: &Attestation,
+) -> BytesN<64> {
+    let digest = test.client.get_attestation_digest(attestation);
+    let signature = signing_key.sign(&digest.to_array());
+    BytesN::from_array(&test.env, &signature.to_bytes())
+}
+
+#[test]
+fn test_attestation_verifies() {
     let test = TestEnv::new();
     let recorder = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    let (signing_key, public_key) = attester_key(&test.env, 7);
+    test.client
+        .set_attester(&test.admin, &Some(public_key.clone()));
+
+    let donation = EventType::Donation.as_u32();
+
+    let user = Address::generate(&test.env);
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &200, &None);
+    test.env.ledger().set_timestamp(1000);
+
+    let attestation = test.client.get_attestation(&user, &DAY_IN_SECONDS);
+    assert_eq!(attestation.user, user);
+    assert_eq!(attestation.level, Level::Bronze.as_u32());
+    assert_eq!(attestation.points, 200);
+    assert_eq!(attestation.ledger, test.env.ledger().sequence());
+    assert_eq!(attestation.expires_at, 1000 + DAY_IN_SECONDS);
+    assert_eq!(attestation.attester, public_key);
+
+    let signature = sign_attestation(&test, &signing_key, &attestation);
+    assert!(test.client.verify_attestation(&attestation, &signature));
+}
+
+#[test]
+fn test_expired_attestation_is_rejected() {
+    let test = TestEnv::new();
+    let (signing_key, public_key) = attester_key(&test.env, 7);
+    test.client.set_attester(&test.admin, &Some(public_key));
+
     let user = Address::generate(&test.env);
+    let attestation = test.client.get_attestation(&user, &3600);
+    let signature = sign_attestation(&test, &signing_key, &attestation);
 
-    test.grant_recorder_role(&recorder);
+    assert!(test.client.verify_attestation(&attestation, &signature));
+    test.env.ledger().set_timestamp(3601);
+    assert!(!test.client.verify_attestation(&attestation, &signature));
+}
 
-    // Record some events
-    test.client.record_event(&recorder, &user, &EventType::Donation);
-    test.client.record_event(&recorder, &user, &EventType::QuestCompletion);
+#[test]
+#[should_panic]
+fn test_tampered_attestation_fails() {
+    let test = TestEnv::new();
+    let (signing_key, public_key) = attester_key(&test.env, 7);
+    test.client.set_attester(&test.admin, &Some(public_key));
+
+    let user = Address::generate(&test.env);
+    let mut attestation = test.client.get_attestation(&user, &3600);
+    let signature = sign_attestation(&test, &signing_key, &attestation);
 
-    let events = test.client.get_user_events(&user);
+    attestation.level = Level::Diamond.as_u32();
+    test.client.verify_attestation(&attestation, &signature);
+}
 
-    assert_eq!(events.len(), 2);
-    assert_eq!(events.get(0).unwrap().event_type, EventType::Donation);
-    assert_eq!(events.get(1).unwrap().event_type, EventType::QuestCompletion);
+#[test]
+fn test_attestation_from_rotated_key_is_rejected() {
+    let test = TestEnv::new();
+    let (signing_key, public_key) = attester_key(&test.env, 7);
+    test.client.set_attester(&test.admin, &Some(public_key));
+
+    let user = Address::generate(&test.env);
+    let attestation = test.client.get_attestation(&user, &3600);
+    let signature = sign_attestation(&test, &signing_key, &attestation);
+
+    let (_, rotated_key) = attester_key(&test.env, 8);
+    test.client.set_attester(&test.admin, &Some(rotated_key));
+    assert!(!test.client.verify_attestation(&attestation, &signature));
+
+    // Removing the key rejects every attestation
+    test.client.set_attester(&test.admin, &None);
+    assert!(!test.client.verify_attestation(&attestation, &signature));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #433)")] // AttesterNotSet
+fn test_get_attestation_without_attester_fails() {
+    let test = TestEnv::new();
+    let user = Address::generate(&test.env);
+    test.client.get_attestation(&user, &3600);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #436)")] // InvalidAttestationValidity
+fn test_attestation_validity_above_maximum_fails() {
+    let test = TestEnv::new();
+    let (_, public_key) = attester_key(&test.env, 7);
+    test.client.set_attester(&test.admin, &Some(public_key));
+
+    let user = Address::generate(&test.env);
+    test.client
+        .get_attestation(&user, &(MAX_ATTESTATION_VALIDITY + 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #401)")] // Unauthorized
+fn test_set_attester_requires_admin() {
+    let test = TestEnv::new();
+    let (_, public_key) = attester_key(&test.env, 7);
+    let other = Address::generate(&test.env);
+    test.client.set_attester(&other, &Some(public_key));
 }
 
 // ============================================================================
@@ -477,6 +2333,540 @@ fn test_set_nft_contract_unauthorized_fails() {
     test.client.set_nft_contract(&unauthorized, &nft_contract);
 }
 
+#[test]
+fn test_on_nft_transfer_rebinds_token() {
+    let (test, _nft) = TestEnv::new_with_mock_nft();
+    let recorder = Address::generate(&test.env);
+    let from = Address::generate(&test.env);
+    let to = Address::generate(&test.env);
+
+    test.grant_recorder_role(&recorder);
+    test.client.register_user_nft(&recorder, &from, &7);
+
+    test.client.on_nft_transfer(&7, &from, &Some(to.clone()));
+
+    assert!(test.client.get_user_nft_token_id(&from).is_none());
+    assert_eq!(test.client.get_user_nft_token_id(&to), Some(7));
+}
+
+#[test]
+fn test_on_nft_transfer_queues_recipient_sync() {
+    let (test, _nft) = TestEnv::new_with_mock_nft();
+    let recorder = Address::generate(&test.env);
+    let from = Address::generate(&test.env);
+    let to = Address::generate(&test.env);
+    let holder = Address::generate(&test.env);
+
+    test.grant_recorder_role(&recorder);
+    test.client.register_user_nft(&recorder, &from, &7);
+    test.client.register_user_nft(&recorder, &holder, &8);
+
+    test.client.on_nft_transfer(&7, &from, &Some(to.clone()));
+    // A recipient that keeps its own token is not queued
+    test.client.on_nft_transfer(&9, &from, &Some(holder.clone()));
+
+    assert_eq!(
+        test.client.get_pending_syncs(&0, &10),
+        vec![&test.env, to.clone()]
+    );
+    assert!(!test.client.is_sync_pending(&holder));
+}
+
+#[test]
+fn test_on_nft_transfer_keeps_existing_recipient_token() {
+    let (test, _nft) = TestEnv::new_with_mock_nft();
+    let recorder = Address::generate(&test.env);
+    let from = Address::generate(&test.env);
+    let to = Address::generate(&test.env);
+
+    test.grant_recorder_role(&recorder);
+    test.client.register_user_nft(&recorder, &from, &7);
+    test.client.register_user_nft(&recorder, &to, &8);
+
+    test.client.on_nft_transfer(&7, &from, &Some(to.clone()));
+
+    assert!(test.client.get_user_nft_token_id(&from).is_none());
+    assert_eq!(test.client.get_user_nft_token_id(&to), Some(8));
+}
+
+#[test]
+fn test_on_nft_burn_clears_mapping() {
+    let (test, _nft) = TestEnv::new_with_mock_nft();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+
+    test.grant_recorder_role(&recorder);
+    test.client.register_user_nft(&recorder, &user, &7);
+
+    test.client.on_nft_transfer(&7, &user, &None);
+
+    assert!(test.client.get_user_nft_token_id(&user).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #407)")] // NFTContractNotSet
+fn test_on_nft_transfer_without_nft_contract_fails() {
+    let test = TestEnv::new();
+    let from = Address::generate(&test.env);
+
+    test.client.on_nft_transfer(&7, &from, &None);
+}
+
+// ============================================================================
+// Auto-Mint Tests
+// ============================================================================
+
+/// Minimal stand-in for the KindFi NFT contract, exposing the entry points
+/// the Reputation contract calls.
+#[contract]
+struct MockNFT;
+
+#[contractimpl]
+impl MockNFT {
+    pub fn mint_with_metadata(e: Env, caller: Address, to: Address, metadata: NFTMetadata) -> u32 {
+        caller.require_auth();
+        let token_id = Self::minted_count(e.clone());
+        e.storage()
+            .instance()
+            .set(&symbol_short!("count"), &(token_id + 1));
+        e.storage().persistent().set(&(symbol_short!("owner"), token_id), &to);
+        e.storage().persistent().set(&(symbol_short!("meta"), token_id), &metadata);
+        token_id
+    }
+
+    pub fn owner_of(e: Env, token_id: u32) -> Address {
+        e.storage()
+            .persistent()
+            .get(&(symbol_short!("owner"), token_id))
+            .unwrap()
+    }
+
+    pub fn update_metadata(e: Env, caller: Address, token_id: u32, metadata: NFTMetadata) {
+        caller.require_auth();
+        Self::check_unlocked(&e);
+        e.storage().persistent().set(&(symbol_short!("meta"), token_id), &metadata);
+    }
+
+    pub fn set_attributes(e: Env, caller: Address, token_id: u32, attributes: Vec<NFTAttribute>) {
+        caller.require_auth();
+        Self::check_unlocked(&e);
+        let mut metadata = Self::get_metadata(e.clone(), token_id).unwrap();
+        for attribute in attributes.iter() {
+            let existing = metadata
+                .attributes
+                .iter()
+                .position(|a| a.trait_type == attribute.trait_type);
+            match existing {
+                Some(index) => metadata.attributes.set(index as u32, attribute),
+                None => metadata.attributes.push_back(attribute),
+            }
+        }
+        e.storage().persistent().set(&(symbol_short!("meta"), token_id), &metadata);
+    }
+
+    pub fn get_metadata(e: Env, token_id: u32) -> Option<NFTMetadata> {
+        e.storage().persistent().get(&(symbol_short!("meta"), token_id))
+    }
+
+    pub fn minted_count(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&symbol_short!("count"))
+            .unwrap_or(0)
+    }
+
+    /// Make metadata updates fail, as if the Reputation contract lost its
+    /// metadata_manager role.
+    pub fn set_locked(e: Env, locked: bool) {
+        e.storage()
+            .instance()
+            .set(&symbol_short!("locked"), &locked);
+    }
+}
+
+impl MockNFT {
+    fn check_unlocked(e: &Env) {
+        if e.storage()
+            .instance()
+            .get(&symbol_short!("locked"))
+            .unwrap_or(false)
+        {
+            panic_with_error!(e, soroban_sdk::Error::from_contract_error(1));
+        }
+    }
+}
+
+impl TestEnv {
+    /// Create a test environment wired to a mock NFT contract.
+    fn new_with_mock_nft() -> (Self, MockNFTClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let nft_id = env.register(MockNFT, ());
+        let nft = MockNFTClient::new(&env, &nft_id);
+
+        let contract_id = env.register(Reputation, (&admin, Some(nft_id)));
+        let client = ReputationClient::new(&env, &contract_id);
+
+        (TestEnv { env, admin, client }, nft)
+    }
+
+    /// Create a test environment wired to a mock NFT contract with auto-mint
+    /// enabled and a Rookie badge template.
+    fn new_with_auto_mint() -> (Self, MockNFTClient<'static>) {
+        let (test, nft) = TestEnv::new_with_mock_nft();
+
+        let config = Address::generate(&test.env);
+        test.grant_config_role(&config);
+        test.client
+            .set_level_template(&config, &Level::Rookie.as_u32(), &test.level_template("Rookie"));
+        test.client.set_auto_mint(&config, &true);
+
+        (test, nft)
+    }
+
+    fn level_template(&self, name: &str) -> LevelTemplate {
+        LevelTemplate {
+            name: String::from_str(&self.env, name),
+            description: String::from_str(&self.env, "KindFi reputation badge"),
+            image_uri: String::from_str(&self.env, "ipfs://QmBadge"),
+        }
+    }
+}
+
+#[test]
+fn test_first_event_mints_badge() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+    assert_eq!(nft.owner_of(&token_id), user);
+
+    let metadata = nft.get_metadata(&token_id).unwrap();
+    assert_eq!(metadata.name, String::from_str(&test.env, "Rookie"));
+    assert!(metadata.attributes.contains(NFTAttribute {
+        trait_type: String::from_str(&test.env, "points"),
+        value: String::from_str(&test.env, "10"),
+        display_type: Some(String::from_str(&test.env, "number")),
+        max_value: None,
+    }));
+}
+
+#[test]
+fn test_auto_mint_only_on_first_event() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+
+    assert_eq!(nft.minted_count(), 1);
+    assert_eq!(test.client.get_user_nft_token_id(&user), Some(0));
+}
+
+#[test]
+fn test_auto_mint_retried_after_failed_mint() {
+    let (test, nft) = TestEnv::new_with_mock_nft();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    // No Rookie template yet, so the first event cannot mint
+    test.client.set_auto_mint(&config, &true);
+    test.client.record_event(&recorder, &user, &donation, &None);
+    assert!(test.client.get_user_nft_token_id(&user).is_none());
+
+    test.client.set_level_template(
+        &config,
+        &Level::Rookie.as_u32(),
+        &test.level_template("Rookie"),
+    );
+    test.client.record_event(&recorder, &user, &donation, &None);
+
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+    assert_eq!(nft.owner_of(&token_id), user);
+    assert!(nft
+        .get_metadata(&token_id)
+        .unwrap()
+        .attributes
+        .contains(crate::nft_client::build_points_attribute(&test.env, 20)));
+}
+
+#[test]
+fn test_auto_mint_disabled_by_default() {
+    let (test, nft) = TestEnv::new_with_mock_nft();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+
+    assert!(!test.client.is_auto_mint_enabled());
+    assert_eq!(nft.minted_count(), 0);
+    assert!(test.client.get_user_nft_token_id(&user).is_none());
+}
+
+#[test]
+fn test_level_up_applies_level_template() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let config = Address::generate(&test.env);
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_config_role(&config);
+    test.grant_recorder_role(&recorder);
+
+    let bronze = LevelTemplate {
+        name: String::from_str(&test.env, "Bronze"),
+        description: String::from_str(&test.env, "Bronze supporter"),
+        image_uri: String::from_str(&test.env, "ipfs://QmBronze"),
+    };
+    test.client
+        .set_level_template(&config, &Level::Bronze.as_u32(), &bronze);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &200, &None);
+
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+    let metadata = nft.get_metadata(&token_id).unwrap();
+    assert_eq!(metadata.name, bronze.name);
+    assert_eq!(metadata.description, bronze.description);
+    assert_eq!(metadata.image_uri, bronze.image_uri);
+    assert!(metadata
+        .attributes
+        .contains(test.level_attribute(Level::Bronze)));
+}
+
+#[test]
+fn test_level_up_without_template_keeps_artwork() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event_with_points(&recorder, &user, &EventType::Donation.as_u32(), &200, &None);
+
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+    let metadata = nft.get_metadata(&token_id).unwrap();
+    assert_eq!(metadata.name, String::from_str(&test.env, "Rookie"));
+    assert!(metadata
+        .attributes
+        .contains(test.level_attribute(Level::Bronze)));
+    assert!(metadata
+        .attributes
+        .contains(crate::nft_client::build_points_attribute(&test.env, 200)));
+    assert_eq!(metadata.attributes.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2000)")] // Role check failure
+fn test_set_level_template_without_role_fails() {
+    let test = TestEnv::new();
+    let unauthorized = Address::generate(&test.env);
+
+    test.client.set_level_template(
+        &unauthorized,
+        &Level::Rookie.as_u32(),
+        &test.level_template("Rookie"),
+    );
+}
+
+// ============================================================================
+// NFT Sync Tests
+// ============================================================================
+
+#[test]
+fn test_failed_nft_upgrade_is_queued_and_retried() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    test.client.record_event(&recorder, &user, &donation, &None);
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+
+    // Recording still succeeds while the NFT contract rejects updates
+    nft.set_locked(&true);
+    test.client
+        .record_event_with_points(&recorder, &user, &donation, &200, &None);
+    assert!(test.client.is_sync_pending(&user));
+    assert_eq!(
+        test.client.get_pending_syncs(&0, &10),
+        vec![&test.env, user.clone()]
+    );
+
+    nft.set_locked(&false);
+    assert!(test.client.sync_nft(&user));
+    assert!(!test.client.is_sync_pending(&user));
+    assert_eq!(test.client.get_pending_sync_count(), 0);
+    assert!(nft
+        .get_metadata(&token_id)
+        .unwrap()
+        .attributes
+        .contains(test.level_attribute(Level::Bronze)));
+}
+
+#[test]
+fn test_clearing_pending_sync_keeps_others_queued() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+    let donation = EventType::Donation.as_u32();
+
+    let users = [
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+    for user in users.iter() {
+        test.client.record_event(&recorder, user, &donation, &None);
+    }
+    nft.set_locked(&true);
+    for user in users.iter() {
+        test.client.record_event(&recorder, user, &donation, &None);
+    }
+    assert_eq!(test.client.get_pending_sync_count(), 3);
+
+    nft.set_locked(&false);
+    test.client.sync_nft(&users[0]);
+    let pending = test.client.get_pending_syncs(&0, &10);
+    assert_eq!(pending.len(), 2);
+    assert!(pending.contains(&users[1]));
+    assert!(pending.contains(&users[2]));
+
+    // A successful update on the next event also clears the queue entry
+    test.client
+        .record_event(&recorder, &users[2], &donation, &None);
+    assert_eq!(
+        test.client.get_pending_syncs(&0, &10),
+        vec![&test.env, users[1].clone()]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #408)")] // NFTUpgradeFailed
+fn test_sync_nft_fails_while_nft_contract_rejects_updates() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    test.client
+        .record_event(&recorder, &user, &EventType::Donation.as_u32(), &None);
+    nft.set_locked(&true);
+
+    test.client.sync_nft(&user);
+}
+
+#[test]
+fn test_sync_nft_without_registered_nft() {
+    let (test, _nft) = TestEnv::new_with_mock_nft();
+    let user = Address::generate(&test.env);
+
+    assert!(!test.client.sync_nft(&user));
+}
+
+// ============================================================================
+// Batch Recording Tests
+// ============================================================================
+
+#[test]
+fn test_record_events_batch_returns_totals() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let alice = Address::generate(&test.env);
+    let bob = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    let events = vec![
+        &test.env,
+        (alice.clone(), EventType::Donation.as_u32(), None),
+        (bob.clone(), EventType::Donation.as_u32(), Some(150u32)),
+        (alice.clone(), EventType::QuestCompletion.as_u32(), None),
+        (bob.clone(), EventType::Donation.as_u32(), Some(50u32)),
+    ];
+    let totals = test.client.record_events_batch(&recorder, &events);
+
+    assert_eq!(totals.len(), 2);
+    assert_eq!(totals.get(alice.clone()), Some(40));
+    assert_eq!(totals.get(bob.clone()), Some(200));
+    assert_eq!(test.client.get_level(&bob), Level::Bronze.as_u32());
+    assert_eq!(test.client.get_user_event_count(&alice), 2);
+    assert_eq!(test.client.get_user_event_count(&bob), 2);
+}
+
+#[test]
+fn test_record_events_batch_syncs_nft_once() {
+    let (test, nft) = TestEnv::new_with_auto_mint();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    let events = vec![
+        &test.env,
+        (user.clone(), EventType::Donation.as_u32(), None),
+        (user.clone(), EventType::Donation.as_u32(), None),
+        (user.clone(), EventType::Donation.as_u32(), Some(180u32)),
+    ];
+    test.client.record_events_batch(&recorder, &events);
+
+    // A single badge is minted and then upgraded to the final level
+    assert_eq!(nft.minted_count(), 1);
+    let token_id = test.client.get_user_nft_token_id(&user).unwrap();
+    let metadata = nft.get_metadata(&token_id).unwrap();
+    assert!(metadata
+        .attributes
+        .contains(test.level_attribute(Level::Bronze)));
+    assert!(metadata.attributes.contains(NFTAttribute {
+        trait_type: String::from_str(&test.env, "points"),
+        value: String::from_str(&test.env, "200"),
+        display_type: Some(String::from_str(&test.env, "number")),
+        max_value: None,
+    }));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #404)")] // InvalidPoints
+fn test_record_events_batch_zero_points_fails() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    let events = vec![&test.env, (user, EventType::Donation.as_u32(), Some(0u32))];
+    test.client.record_events_batch(&recorder, &events);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #413)")] // BatchTooLarge
+fn test_record_events_batch_too_large_fails() {
+    let test = TestEnv::new();
+    let recorder = Address::generate(&test.env);
+    let user = Address::generate(&test.env);
+    test.grant_recorder_role(&recorder);
+
+    let mut events = vec![&test.env];
+    for _ in 0..51 {
+        events.push_back((user.clone(), EventType::Donation.as_u32(), Option::<u32>::None));
+    }
+    test.client.record_events_batch(&recorder, &events);
+}
+
 // ============================================================================
 // Role Management Tests
 // ============================================================================
@@ -523,7 +2913,7 @@ fn test_multiple_roles() {
     // User can now record events and update config
     let target_user = Address::generate(&test.env);
     test.client
-        .record_event(&user, &target_user, &EventType::Donation);
+        .record_event(&user, &target_user, &EventType::Donation.as_u32(), &None);
 
     let thresholds: Map<u32, u32> = Map::new(&test.env);
     test.client.set_level_thresholds(&user, &thresholds);
@@ -537,38 +2927,38 @@ fn test_multiple_roles() {
 fn test_default_event_point_values() {
     let test = TestEnv::new();
 
-    assert_eq!(test.client.get_event_point_value(&EventType::Donation), 10);
+    assert_eq!(test.client.get_event_point_value(&EventType::Donation.as_u32()), 10);
     assert_eq!(
-        test.client.get_event_point_value(&EventType::StreakDonation),
+        test.client.get_event_point_value(&EventType::StreakDonation.as_u32()),
         25
     );
     assert_eq!(
         test.client
-            .get_event_point_value(&EventType::SuccessfulReferral),
+            .get_event_point_value(&EventType::SuccessfulReferral.as_u32()),
         50
     );
     assert_eq!(
         test.client
-            .get_event_point_value(&EventType::NewCategoryDonation),
+            .get_event_point_value(&EventType::NewCategoryDonation.as_u32()),
         15
     );
     assert_eq!(
         test.client
-            .get_event_point_value(&EventType::NewCampaignDonation),
+            .get_event_point_value(&EventType::NewCampaignDonation.as_u32()),
         5
     );
     assert_eq!(
         test.client
-            .get_event_point_value(&EventType::QuestCompletion),
+            .get_event_point_value(&EventType::QuestCompletion.as_u32()),
         30
     );
     assert_eq!(
-        test.client.get_event_point_value(&EventType::BoostedProject),
+        test.client.get_event_point_value(&EventType::BoostedProject.as_u32()),
         20
     );
     assert_eq!(
         test.client
-            .get_event_point_value(&EventType::OutstandingBooster),
+            .get_event_point_value(&EventType::OutstandingBooster.as_u32()),
         100
     );
 }
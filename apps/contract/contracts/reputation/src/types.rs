@@ -1,6 +1,7 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
-/// Event types that can award reputation points.
+/// Ids of the built-in event types. Further types can be registered with
+/// `set_event_type`; all event types are referred to by their u32 id.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -23,6 +24,88 @@ pub enum EventType {
     OutstandingBooster = 7,
 }
 
+impl EventType {
+    /// All built-in event types
+    pub const ALL: [EventType; 8] = [
+        EventType::Donation,
+        EventType::StreakDonation,
+        EventType::SuccessfulReferral,
+        EventType::NewCategoryDonation,
+        EventType::NewCampaignDonation,
+        EventType::QuestCompletion,
+        EventType::BoostedProject,
+        EventType::OutstandingBooster,
+    ];
+
+    /// Convert event type to its u32 id
+    pub fn as_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Get the built-in event type with this id
+    pub fn from_u32(value: u32) -> Option<EventType> {
+        Self::ALL.into_iter().find(|event_type| event_type.as_u32() == value)
+    }
+
+    /// Registry name of the built-in event type
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventType::Donation => "donation",
+            EventType::StreakDonation => "streak_donation",
+            EventType::SuccessfulReferral => "successful_referral",
+            EventType::NewCategoryDonation => "new_category_donation",
+            EventType::NewCampaignDonation => "new_campaign_donation",
+            EventType::QuestCompletion => "quest_completion",
+            EventType::BoostedProject => "boosted_project",
+            EventType::OutstandingBooster => "outstanding_booster",
+        }
+    }
+
+    /// Default points of the built-in event type
+    pub fn default_points(&self) -> u32 {
+        match self {
+            EventType::Donation => DEFAULT_DONATION_POINTS,
+            EventType::StreakDonation => DEFAULT_STREAK_DONATION_POINTS,
+            EventType::SuccessfulReferral => DEFAULT_REFERRAL_POINTS,
+            EventType::NewCategoryDonation => DEFAULT_NEW_CATEGORY_POINTS,
+            EventType::NewCampaignDonation => DEFAULT_NEW_CAMPAIGN_POINTS,
+            EventType::QuestCompletion => DEFAULT_QUEST_COMPLETION_POINTS,
+            EventType::BoostedProject => DEFAULT_BOOSTED_PROJECT_POINTS,
+            EventType::OutstandingBooster => DEFAULT_OUTSTANDING_BOOSTER_POINTS,
+        }
+    }
+}
+
+/// Registry entry of an event type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventTypeConfig {
+    /// Event type name (e.g. "academy_lesson")
+    pub name: String,
+    /// Points awarded by `record_event`
+    pub default_points: u32,
+    /// Whether the event type can currently be recorded
+    pub enabled: bool,
+    /// Minimum seconds between two recordings of this type for the same
+    /// user (0 = no cooldown)
+    pub cooldown_seconds: u64,
+}
+
+/// Reason codes for point deductions.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DeductionReason {
+    /// Fraudulent activity
+    Fraud = 0,
+    /// The donation that earned the points was refunded
+    RefundedDonation = 1,
+    /// Violation of platform policies
+    PolicyViolation = 2,
+    /// Correction of points awarded by mistake
+    Correction = 3,
+}
+
 /// Threshold types for permission-based access.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -38,7 +121,8 @@ pub enum ThresholdType {
     SpecialRewards = 3,
 }
 
-/// Reputation levels with their point thresholds.
+/// Ids of the levels in the default level table. Deployments can replace
+/// the table with their own levels via `set_level_table`.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -80,16 +164,263 @@ impl Level {
     }
 }
 
+/// A level in the level table. Levels are ordered by threshold; the first
+/// one must start at 0 points so every user has a level.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelDefinition {
+    /// Stable level id, used in storage, events and queries
+    pub id: u32,
+    /// Level name, also used as the NFT `level` attribute value
+    pub name: String,
+    /// Points required to reach the level
+    pub threshold: u32,
+}
+
 /// Record of a reputation event for a user.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReputationEventRecord {
-    /// Type of event that occurred
-    pub event_type: EventType,
-    /// Points awarded for this event
+    /// Id of the event type that occurred
+    pub event_type: u32,
+    /// Points awarded, or deducted if `deduction_reason` is set
     pub points: u32,
+    /// Points before bonus campaign multipliers and limits (= `points` for
+    /// deductions)
+    pub base_points: u32,
     /// Timestamp when the event was recorded
     pub timestamp: u64,
+    /// Reason code if this record is a deduction
+    pub deduction_reason: Option<DeductionReason>,
+    /// External reference of the action behind the event (e.g. donation tx hash)
+    pub external_ref: Option<BytesN<32>>,
+}
+
+/// Outcome of an event recorded with an external reference, kept so a
+/// retried call can return the original result.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExternalRefRecord {
+    /// User who received the points
+    pub user: Address,
+    /// Id of the event type recorded
+    pub event_type: u32,
+    /// Points requested, before bonus campaign multipliers and limits
+    pub points: u32,
+    /// User's total points after the event
+    pub new_total_points: u32,
+}
+
+/// Position of a user's event history: records `[first, next)` are kept as
+/// individual entries, older ones are rolled into the per-type summary.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventHistoryIndex {
+    /// Index of the oldest retained record
+    pub first: u32,
+    /// Index the next record will be written at (= total events recorded)
+    pub next: u32,
+}
+
+/// Aggregate of a user's rolled-up records for one event type.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventTypeSummary {
+    /// Number of records rolled up
+    pub event_count: u32,
+    /// Points awarded by the rolled-up records
+    pub points_awarded: u32,
+    /// Points deducted by the rolled-up records
+    pub points_deducted: u32,
+    /// Timestamp of the newest rolled-up record
+    pub last_timestamp: u64,
+}
+
+/// Badge artwork and copy for a level, used when minting reputation NFTs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelTemplate {
+    /// NFT name (e.g. "KindFi Rookie")
+    pub name: String,
+    /// NFT description
+    pub description: String,
+    /// Image URI (ipfs:// or https://)
+    pub image_uri: String,
+}
+
+/// Inactivity decay policy: points lose `decay_bps` for every full
+/// `period_seconds` since the user's last event that awarded points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayPolicy {
+    /// Length of one decay period in seconds (must be > 0)
+    pub period_seconds: u64,
+    /// Share of points lost per period, in basis points (10000 = 100%)
+    pub decay_bps: u32,
+}
+
+/// Raw vs. effective points of a user.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointsBreakdown {
+    /// Points stored for the user, before pending decay
+    pub raw_points: u32,
+    /// Points after applying pending decay
+    pub effective_points: u32,
+    /// Points lost to pending decay (raw - effective)
+    pub decayed_points: u32,
+    /// Timestamp decay is counted from: the user's last event that awarded
+    /// points, moved past any decay already persisted
+    pub last_activity: Option<u64>,
+}
+
+/// Per-user limit on an event type over fixed time windows.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    /// Window length in seconds (e.g. 86400 for a day, 604800 for a week)
+    pub period_seconds: u64,
+    /// Maximum events per user per window (0 = unlimited)
+    pub max_events: u32,
+    /// Maximum points per user per window (0 = unlimited)
+    pub max_points: u32,
+}
+
+/// What happens to an event that exceeds a rate limit or recorder budget.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum LimitMode {
+    /// The call fails
+    Reject = 0,
+    /// The event is recorded with zero points
+    ZeroPoints = 1,
+}
+
+/// Events and points counted in one limit window.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LimitUsage {
+    /// Window index (timestamp / window length)
+    pub window: u64,
+    /// Events counted in the window
+    pub events: u32,
+    /// Points counted in the window
+    pub points: u32,
+}
+
+/// What a user can still receive for an event type in the current window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateAllowance {
+    /// Events left, or None if events are not limited
+    pub events_remaining: Option<u32>,
+    /// Points left, or None if points are not limited
+    pub points_remaining: Option<u32>,
+    /// Timestamp at which the current window ends
+    pub resets_at: u64,
+}
+
+/// A time-boxed points multiplier for some event types.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BonusCampaign {
+    /// Campaign id
+    pub id: u32,
+    /// Event type ids the multiplier applies to
+    pub event_types: Vec<u32>,
+    /// Multiplier in basis points (20000 = 2x)
+    pub multiplier_bps: u32,
+    /// Timestamp the campaign starts at
+    pub start_time: u64,
+    /// Timestamp the campaign ends at (exclusive)
+    pub end_time: u64,
+    /// Whether the campaign's start has been announced
+    pub started: bool,
+}
+
+/// A moderator's proposal to merge one account into another, executable
+/// once its time lock has passed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergeProposal {
+    /// Account whose reputation is moved
+    pub source: Address,
+    /// Account receiving the reputation
+    pub target: Address,
+    /// Moderator who proposed the merge
+    pub proposer: Address,
+    /// Timestamp from which the merge can be executed
+    pub executable_at: u64,
+}
+
+/// A statement of a user's level and points, signed off-chain by the
+/// attester key so partners can check it without calling this contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    /// User the attestation is about
+    pub user: Address,
+    /// User's level id
+    pub level: u32,
+    /// User's points, after decay
+    pub points: u32,
+    /// Ledger sequence the level and points were read at
+    pub ledger: u32,
+    /// Timestamp after which the attestation is no longer valid
+    pub expires_at: u64,
+    /// Ed25519 public key expected to sign the attestation
+    pub attester: BytesN<32>,
+}
+
+/// A user's points and level as of a ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    /// Ledger sequence at which the values were set
+    pub ledger: u32,
+    /// Total points after the change
+    pub points: u32,
+    /// Level id after the change
+    pub level: u32,
+}
+
+/// A user's position on a leaderboard.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    /// User on the board
+    pub user: Address,
+    /// User's points (lifetime or season) as of their last change
+    pub points: u32,
+}
+
+/// A reputation season (e.g. a quarterly campaign).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    /// Season number, starting at 1
+    pub id: u32,
+    /// Display name (e.g. "Season 3")
+    pub name: String,
+    /// Timestamp when the season started
+    pub start_time: u64,
+    /// Timestamp when the season ended, None while it is active
+    pub end_time: Option<u64>,
+}
+
+/// A user's standing in a season.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonStanding {
+    /// Season id
+    pub season: u32,
+    /// Net points earned during the season
+    pub points: u32,
+    /// Level reached by the season points, from the season's level table
+    pub level: LevelDefinition,
+    /// Whether the season has ended, so the standing is final
+    pub finalized: bool,
 }
 
 /// Storage keys for the Reputation contract.
@@ -102,22 +433,87 @@ pub enum StorageKey {
     NFTContract,
     /// User's total points: UserPoints(Address) -> u32
     UserPoints(Address),
-    /// User's current level: UserLevel(Address) -> Level
+    /// User's current level id: UserLevel(Address) -> u32
     UserLevel(Address),
     /// User's NFT token ID: UserNFTTokenId(Address) -> u32
     UserNFTTokenId(Address),
-    /// User's event history: UserEvents(Address) -> Vec<ReputationEventRecord>
-    UserEvents(Address),
-    /// Level threshold configuration: LevelThreshold(Level) -> u32
-    LevelThreshold(Level),
-    /// Event point values: EventPointValue(EventType) -> u32
-    EventPointValue(EventType),
-    /// Permission thresholds: PermissionThreshold(ThresholdType) -> Level
+    /// User's event history position: UserEventIndex(Address) -> EventHistoryIndex
+    UserEventIndex(Address),
+    /// One history record: UserEvent(Address, index) -> ReputationEventRecord
+    UserEvent(Address, u32),
+    /// Rolled-up history: UserEventSummary(Address) -> Map<event type id, EventTypeSummary>
+    UserEventSummary(Address),
+    /// Level table: LevelTable -> Vec<LevelDefinition>
+    LevelTable,
+    /// Event type registry entry: EventTypeConfig(event type id) -> EventTypeConfig
+    EventTypeConfig(u32),
+    /// Ids of registered custom event types: CustomEventTypes -> Vec<u32>
+    CustomEventTypes,
+    /// Last recording of an event type for a user: LastEventOfType(Address, id) -> u64
+    LastEventOfType(Address, u32),
+    /// Timestamp a user's decay is counted from: LastActivity(Address) -> u64
+    LastActivity(Address),
+    /// Permission thresholds: PermissionThreshold(ThresholdType) -> level id
     PermissionThreshold(ThresholdType),
     /// Contract initialized flag
     Initialized,
+    /// Whether a badge is minted for users without an NFT
+    AutoMintEnabled,
+    /// Badge template per level: LevelTemplate(level id) -> LevelTemplate
+    LevelTemplate(u32),
+    /// Inactivity decay policy
+    DecayPolicy,
+    /// Recorded external reference: ExternalRef(BytesN<32>) -> ExternalRefRecord (temporary)
+    ExternalRef(BytesN<32>),
+    /// Maximum number of history records kept per user
+    HistoryRetention,
+    /// Per-user rate limit of an event type: RateLimit(event type id) -> RateLimit
+    RateLimit(u32),
+    /// User's usage of an event type's limit: RateLimitUsage(Address, id) -> LimitUsage
+    RateLimitUsage(Address, u32),
+    /// Daily points budget of each recorder: RecorderBudget -> u32
+    RecorderBudget,
+    /// Recorder's usage of its daily budget: RecorderUsage(Address) -> LimitUsage
+    RecorderUsage(Address),
+    /// Handling of events over a limit: LimitMode -> LimitMode
+    LimitMode,
+    /// Id of the latest season (0 before the first): CurrentSeason -> u32
+    CurrentSeason,
+    /// Season info: Season(id) -> Season
+    Season(u32),
+    /// User's points in a season: SeasonPoints(id, Address) -> u32
+    SeasonPoints(u32, Address),
+    /// Level table frozen at season end: SeasonLevelTable(id) -> Vec<LevelDefinition>
+    SeasonLevelTable(u32),
+    /// Number of checkpoints of a user: CheckpointCount(Address) -> u32
+    CheckpointCount(Address),
+    /// One checkpoint: Checkpoint(Address, index) -> Checkpoint
+    Checkpoint(Address, u32),
+    /// Lifetime leaderboard: Leaderboard -> Vec<LeaderboardEntry>
+    Leaderboard,
+    /// Season leaderboard: SeasonLeaderboard(id) -> Vec<LeaderboardEntry>
+    SeasonLeaderboard(u32),
+    /// Number of users kept on each leaderboard: LeaderboardSize -> u32
+    LeaderboardSize,
+    /// Scheduled and running bonus campaigns: BonusCampaigns -> Map<id, BonusCampaign>
+    BonusCampaigns,
+    /// Id of the next bonus campaign: NextBonusCampaignId -> u32
+    NextBonusCampaignId,
+    /// Number of users whose NFT is out of sync: PendingSyncCount -> u32
+    PendingSyncCount,
+    /// User in a pending NFT sync slot: PendingSync(slot) -> Address
+    PendingSync(u32),
+    /// Slot of a user's pending NFT sync: PendingSyncSlot(user) -> u32
+    PendingSyncSlot(Address),
+    /// Pending merge of an account: MergeProposal(source) -> MergeProposal
+    MergeProposal(Address),
+    /// Ed25519 public key that signs attestations: AttesterKey -> BytesN<32>
+    AttesterKey,
 }
 
+/// Maximum number of custom event types in the registry
+pub const MAX_CUSTOM_EVENT_TYPES: u32 = 32;
+
 /// Default point values for each event type
 pub const DEFAULT_DONATION_POINTS: u32 = 10;
 pub const DEFAULT_STREAK_DONATION_POINTS: u32 = 25;
@@ -128,9 +524,36 @@ pub const DEFAULT_QUEST_COMPLETION_POINTS: u32 = 30;
 pub const DEFAULT_BOOSTED_PROJECT_POINTS: u32 = 20;
 pub const DEFAULT_OUTSTANDING_BOOSTER_POINTS: u32 = 100;
 
+/// Maximum number of levels in the level table
+pub const MAX_LEVELS: u32 = 20;
+
 /// Default level thresholds
 pub const ROOKIE_THRESHOLD: u32 = 0;
 pub const BRONZE_THRESHOLD: u32 = 200;
 pub const SILVER_THRESHOLD: u32 = 500;
 pub const GOLD_THRESHOLD: u32 = 1000;
 pub const DIAMOND_THRESHOLD: u32 = 5000;
+
+/// Default number of history records kept per user before rolling up
+pub const DEFAULT_HISTORY_RETENTION: u32 = 100;
+
+/// Default number of users kept on each leaderboard
+pub const DEFAULT_LEADERBOARD_SIZE: u32 = 100;
+
+/// Maximum number of users kept on each leaderboard
+pub const MAX_LEADERBOARD_SIZE: u32 = 200;
+
+/// Maximum number of scheduled and running bonus campaigns
+pub const MAX_BONUS_CAMPAIGNS: u32 = 10;
+
+/// Maximum bonus campaign multiplier, in basis points (10x)
+pub const MAX_MULTIPLIER_BPS: u32 = 100_000;
+
+/// Delay between proposing and executing a moderator merge (three days)
+pub const MERGE_TIME_LOCK: u64 = 3 * 86_400;
+
+/// Longest validity of an attestation (30 days)
+pub const MAX_ATTESTATION_VALIDITY: u64 = 30 * 86_400;
+
+/// Window length of the recorder budget (one day)
+pub const RECORDER_BUDGET_PERIOD: u64 = 86_400;